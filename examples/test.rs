@@ -8,6 +8,7 @@ fn main() {
     ];
     let mut flat = RowGrid1D::new((4, 3).into(), vec).unwrap();
     println!("SIZE {:?}", flat.size());
+    println!("{}", flat.display().separator(" "));
 
     // ITEM
     print!("ITEM    : ");
@@ -69,8 +70,15 @@ fn main() {
         print!("({})", col.collect::<String>());
     }
     print!("\nCOLS MUT: ");
-    for col in (&mut flat).cols(..).unwrap() {
-        print!("({})", col.collect::<String>());
+    for col in 0..4 {
+        print!(
+            "({})",
+            (&mut flat)
+                .col(col)
+                .unwrap()
+                .map(|c| *c)
+                .collect::<String>()
+        );
     }
 
     println!("\n=======================");
@@ -143,8 +151,15 @@ fn main() {
         print!("({})", col.collect::<String>());
     }
     print!("\nCOLS MUT: ");
-    for col in (&mut flat).cols(..).unwrap() {
-        print!("({})", col.collect::<String>());
+    for col in 0..4 {
+        print!(
+            "({})",
+            (&mut flat)
+                .col(col)
+                .unwrap()
+                .map(|c| *c)
+                .collect::<String>()
+        );
     }
 
     println!();
@@ -0,0 +1,283 @@
+//! ANSI escape sequence rendering of styled grids.
+//!
+//! A [`Renderer`](Renderer) writes grids of `(char, Style)` items to any
+//! [`Write`](std::io::Write)r, tracking the terminal's cursor and style to
+//! emit as few sequences as possible.
+
+use crate::{
+    cell::{Attributes, Color},
+    *,
+};
+use std::{
+    borrow::Borrow,
+    io::{self, Write},
+    vec::Vec,
+};
+use unicode_width::UnicodeWidthChar;
+
+/// SGR codes to set and unset each attribute.
+const ATTRIBUTES: [(Attributes, u8, u8); 8] = [
+    (Attributes::BOLD, 1, 22),
+    (Attributes::DIM, 2, 22),
+    (Attributes::ITALIC, 3, 23),
+    (Attributes::UNDERLINE, 4, 24),
+    (Attributes::BLINK, 5, 25),
+    (Attributes::REVERSE, 7, 27),
+    (Attributes::HIDDEN, 8, 28),
+    (Attributes::STRIKE, 9, 29),
+];
+
+/// Renders styled grids as ANSI/VT100 escape sequences.
+///
+/// The renderer assumes it is the only one writing to the terminal: the
+/// cursor position and the current style are remembered between calls. When
+/// unknown (initially), they are set unconditionally.
+#[derive(Debug)]
+pub struct Renderer<W> {
+    out:    W,
+    cursor: Option<Point>,
+    style:  Option<Style>,
+}
+
+impl<W: Write> Renderer<W> {
+    /// Creates a new `Renderer` writing to `out`.
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            cursor: None,
+            style: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.out
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// Writing directly invalidates the tracked cursor and style, see
+    /// [`invalidate`](Renderer::invalidate).
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.out
+    }
+
+    /// Consumes the `Renderer`, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Forgets the tracked cursor and style, e.g. after something else wrote
+    /// to the terminal.
+    pub fn invalidate(&mut self) {
+        self.cursor = None;
+        self.style = None;
+    }
+
+    /// Renders the whole `grid`, with its top left corner at the terminal's
+    /// top left corner.
+    pub fn render<G>(&mut self, grid: G) -> io::Result<()>
+    where
+        G: GridRows,
+        G::Item: Borrow<(char, Style)>,
+    {
+        // SAFETY: RangeFull is always in bounds
+        for (y, row) in unsafe { grid.rows_unchecked(..) }.into_iter().enumerate() {
+            self.render_run(Point { x: 0, y }, row)?;
+        }
+
+        self.out.flush()
+    }
+
+    /// Renders `changes`, e.g. from [`diff`](crate::diff()).
+    pub fn render_changes<T>(
+        &mut self,
+        changes: impl IntoIterator<Item = Change<T>>,
+    ) -> io::Result<()>
+    where
+        T: Borrow<(char, Style)>,
+    {
+        for Change { at, items } in changes {
+            self.render_run(at, items)?;
+        }
+
+        self.out.flush()
+    }
+
+    /// Resets the terminal's style to the default.
+    pub fn reset(&mut self) -> io::Result<()> {
+        self.out.write_all(b"\x1b[0m")?;
+        self.style = Some(Style::default());
+        self.out.flush()
+    }
+
+    /// Renders the `items` of a row, from `at`.
+    fn render_run<T>(&mut self, at: Point, items: impl IntoIterator<Item = T>) -> io::Result<()>
+    where
+        T: Borrow<(char, Style)>,
+    {
+        let mut at = at;
+
+        for item in items {
+            let (c, style) = *item.borrow();
+
+            self.move_to(at)?;
+            self.set_style(style)?;
+
+            let mut buf = [0; 4];
+            self.out.write_all(c.encode_utf8(&mut buf).as_bytes())?;
+
+            // Terminals disagree on other widths
+            at.x += 1;
+            self.cursor = if c.width() == Some(1) { Some(at) } else { None };
+        }
+
+        Ok(())
+    }
+
+    fn move_to(&mut self, at: Point) -> io::Result<()> {
+        if self.cursor == Some(at) {
+            return Ok(());
+        }
+
+        write!(self.out, "\x1b[{};{}H", at.y + 1, at.x + 1)?;
+        self.cursor = Some(at);
+        Ok(())
+    }
+
+    fn set_style(&mut self, style: Style) -> io::Result<()> {
+        let mut codes = Vec::new();
+        let current = match self.style {
+            Some(current) if current == style => return Ok(()),
+            Some(current) => current,
+            None => {
+                codes.push(0);
+                Style::default()
+            }
+        };
+
+        // Unset attributes, then set (some unset codes unset two attributes)
+        let mut attributes = current.attributes;
+        for (attribute, _, unset) in ATTRIBUTES.iter() {
+            if attributes.contains(*attribute) && !style.attributes.contains(*attribute) {
+                codes.push(*unset as u32);
+
+                for (other, _, other_unset) in ATTRIBUTES.iter() {
+                    if other_unset == unset {
+                        attributes.remove(*other);
+                    }
+                }
+            }
+        }
+        for (attribute, set, _) in ATTRIBUTES.iter() {
+            if style.attributes.contains(*attribute) && !attributes.contains(*attribute) {
+                codes.push(*set as u32);
+            }
+        }
+
+        if style.foreground != current.foreground {
+            color(&mut codes, style.foreground, 30, 90, 38, 39);
+        }
+        if style.background != current.background {
+            color(&mut codes, style.background, 40, 100, 48, 49);
+        }
+
+        self.style = Some(style);
+
+        if codes.is_empty() {
+            return Ok(());
+        }
+
+        self.out.write_all(b"\x1b[")?;
+        for (i, code) in codes.iter().enumerate() {
+            if i != 0 {
+                self.out.write_all(b";")?;
+            }
+            write!(self.out, "{}", code)?;
+        }
+        self.out.write_all(b"m")
+    }
+}
+
+/// Pushes the SGR codes of `color`.
+fn color(
+    codes: &mut Vec<u32>,
+    color: Option<Color>,
+    ansi: u32,
+    bright: u32,
+    extended: u32,
+    default: u32,
+) {
+    match color {
+        None => codes.push(default),
+        Some(Color::Ansi(n)) if n < 8 => codes.push(ansi + n as u32),
+        Some(Color::Ansi(n)) if n < 16 => codes.push(bright + n as u32 - 8),
+        Some(Color::Ansi(n)) | Some(Color::Indexed(n)) => codes.extend(&[extended, 5, n as u32]),
+        Some(Color::Rgb(r, g, b)) => codes.extend(&[extended, 2, r as u32, g as u32, b as u32]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{string::String, vec, vec::Vec};
+
+    fn grid(items: &str, style: Style) -> RowGrid1D<(char, Style), Vec<(char, Style)>> {
+        grid_with((items.chars().count() / 2, 2), items, |c| (c, style))
+    }
+
+    fn output(renderer: &Renderer<Vec<u8>>) -> String {
+        String::from_utf8(renderer.get_ref().clone()).unwrap()
+    }
+
+    #[test]
+    fn render() {
+        let mut renderer = Renderer::new(Vec::new());
+        let mut grid = grid("abcd", Style::default());
+
+        renderer.render(&grid).unwrap();
+        assert_eq!(output(&renderer), "\x1b[1;1H\x1b[0mab\x1b[2;1Hcd");
+
+        let bold = Style {
+            foreground: Some(Color::Ansi(9)),
+            background: Some(Color::Indexed(200)),
+            attributes: Attributes::BOLD | Attributes::DIM,
+        };
+        let italic = Style {
+            foreground: Some(Color::Rgb(1, 2, 3)),
+            background: Some(Color::Indexed(200)),
+            attributes: Attributes::DIM | Attributes::ITALIC,
+        };
+        grid.as_mut()[1] = ('x', bold);
+        grid.as_mut()[2] = ('y', italic);
+        grid.as_mut()[3] = ('z', italic);
+
+        renderer.get_mut().clear();
+        renderer.render(&grid).unwrap();
+        assert_eq!(
+            output(&renderer),
+            "\x1b[1;1Ha\x1b[1;2;91;48;5;200mx\x1b[2;1H\x1b[22;2;3;38;2;1;2;3myz"
+        );
+    }
+
+    #[test]
+    fn render_changes() {
+        let mut renderer = Renderer::new(Vec::new());
+        let old = grid("abcdef", Style::default());
+        let new = grid("aXcYZf", Style::default());
+
+        renderer.reset().unwrap();
+        renderer.render_changes(diff_slices(&old, &new)).unwrap();
+        assert_eq!(output(&renderer), "\x1b[0m\x1b[1;2HX\x1b[2;1HYZ");
+
+        renderer.get_mut().clear();
+        renderer
+            .render_changes(vec![Change {
+                at:    Point { x: 2, y: 1 },
+                items: vec![('!', Style::default())],
+            }])
+            .unwrap();
+        assert_eq!(output(&renderer), "!");
+    }
+}
@@ -0,0 +1,387 @@
+use crate::*;
+use core::{
+    iter::Flatten,
+    marker::PhantomData,
+    ops::Range,
+    slice::{Iter, IterMut},
+};
+
+/// A fixed-size, row-major grid of `W` columns and `H` rows, stored inline
+/// as `[[I; W]; H]`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ArrayGrid<I, const W: usize, const H: usize> {
+    items: [[I; W]; H],
+}
+
+/// ### Constructors
+impl<I, const W: usize, const H: usize> ArrayGrid<I, W, H> {
+    /// The size of the grid.
+    pub const SIZE: Size = Size { x: W, y: H };
+
+    /// Creates a new [`ArrayGrid`](ArrayGrid) from its rows.
+    pub const fn new(items: [[I; W]; H]) -> Self {
+        Self { items }
+    }
+
+    /// Creates a new [`ArrayGrid`](ArrayGrid) with the items returned by
+    /// `f` for each point.
+    pub fn from_fn(mut f: impl FnMut(Point) -> I) -> Self {
+        Self::new(core::array::from_fn(|y| {
+            core::array::from_fn(|x| f(Point { x, y }))
+        }))
+    }
+
+    /// Returns the underlying rows.
+    pub fn into_inner(self) -> [[I; W]; H] {
+        self.items
+    }
+}
+
+/// ### Accessors
+impl<I, const W: usize, const H: usize> ArrayGrid<I, W, H> {
+    /// Returns the item at `(X, Y)`, checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use grid::ArrayGrid;
+    /// let grid = ArrayGrid::new([[0; 3]; 3]);
+    /// grid.get::<3, 0>();
+    /// ```
+    pub const fn get<const X: usize, const Y: usize>(&self) -> &I {
+        const { assert!(X < W && Y < H, "Index out of bounds") };
+
+        &self.items[Y][X]
+    }
+
+    /// Returns the mutable item at `(X, Y)`, checked at compile time.
+    pub fn get_mut<const X: usize, const Y: usize>(&mut self) -> &mut I {
+        const { assert!(X < W && Y < H, "Index out of bounds") };
+
+        &mut self.items[Y][X]
+    }
+}
+
+impl<I, const W: usize, const H: usize> From<[[I; W]; H]> for ArrayGrid<I, W, H> {
+    fn from(items: [[I; W]; H]) -> Self {
+        Self::new(items)
+    }
+}
+
+impl<I, const W: usize, const H: usize> AsRef<[I]> for ArrayGrid<I, W, H> {
+    fn as_ref(&self) -> &[I] {
+        self.items.as_flattened()
+    }
+}
+
+impl<I, const W: usize, const H: usize> AsMut<[I]> for ArrayGrid<I, W, H> {
+    fn as_mut(&mut self) -> &mut [I] {
+        self.items.as_flattened_mut()
+    }
+}
+
+impl<I, const W: usize, const H: usize> WithSize for ArrayGrid<I, W, H> {
+    fn size(&self) -> Size {
+        Self::SIZE
+    }
+}
+
+macro_rules! grid {
+    ($(
+        $get:ident $iter:ident $ptr:ident
+        $Col:ident $Cols:ident $Rows:ident $(($mut:ident))?;
+    )*) => { $(
+        impl<'a, I, const W: usize, const H: usize> Grid for &'a $($mut)? ArrayGrid<I, W, H> {
+            type Item = &'a $($mut)? I;
+
+            unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+                let Point { x, y } = index.unchecked();
+
+                debug_assert!(x < W && y < H, "Index out of bounds");
+                self.items.$get(y).$get(x)
+            }
+        }
+
+        impl<'a, I, const W: usize, const H: usize> GridRow for &'a $($mut)? ArrayGrid<I, W, H> {
+            type Row = &'a $($mut)? [I];
+
+            unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+                let (y, x) = index.row_unchecked(ArrayGrid::<I, W, H>::SIZE);
+
+                self.items.$get(y).$get(x)
+            }
+        }
+
+        impl<'a, I, const W: usize, const H: usize> GridCol for &'a $($mut)? ArrayGrid<I, W, H> {
+            type Col = $Col<'a, I, W>;
+
+            unsafe fn col_unchecked(self, index: impl Index1D) -> Self::Col {
+                let (x, y) = index.col_unchecked(ArrayGrid::<I, W, H>::SIZE);
+
+                $Col {
+                    items:   self.items.$ptr().cast(),
+                    x,
+                    range:   y,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, I, const W: usize, const H: usize> GridRows for &'a $($mut)? ArrayGrid<I, W, H> {
+            type Rows = $Rows<'a, I, W>;
+
+            unsafe fn rows_unchecked(self, index: impl Index2D) -> Self::Rows {
+                let Rect { x, y } = index.unchecked(ArrayGrid::<I, W, H>::SIZE);
+
+                $Rows {
+                    rows:  self.items.$get(y).$iter(),
+                    range: x,
+                }
+            }
+        }
+
+        impl<'a, I, const W: usize, const H: usize> GridCols for &'a $($mut)? ArrayGrid<I, W, H> {
+            type Cols = $Cols<'a, I, W>;
+
+            unsafe fn cols_unchecked(self, index: impl Index2D) -> Self::Cols {
+                let Rect { x, y } = index.unchecked(ArrayGrid::<I, W, H>::SIZE);
+
+                $Cols {
+                    items:   self.items.$ptr().cast(),
+                    range:   x,
+                    rows:    y,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, I, const W: usize, const H: usize> GridItems for &'a $($mut)? ArrayGrid<I, W, H> {
+            type Items = Flatten<$Rows<'a, I, W>>;
+
+            unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+                self.rows_unchecked(index).flatten()
+            }
+        }
+    )* };
+}
+
+grid!(
+    get_unchecked iter as_ptr ArrayCol ArrayCols ArrayRows;
+    get_unchecked_mut iter_mut as_mut_ptr ArrayColMut ArrayColsMut ArrayRowsMut (mut);
+);
+
+macro_rules! iter {
+    ($(
+        $Col:ident $Cols:ident $Rows:ident $Iter:ident $get:ident $Ptr:ident
+        $mutable:literal $(($mut:ident))?;
+    )*) => { $(
+        #[doc = concat!("Iterator over the ", $mutable, "items of a column of an [`ArrayGrid`](ArrayGrid).")]
+        #[derive(Debug)]
+        pub struct $Col<'a, I, const W: usize> {
+            // Columns share rows, so items are only reached through the
+            // pointer to the first item, never through row borrows
+            items:   *$Ptr I,
+            x:       usize,
+            range:   Range<usize>,
+            phantom: PhantomData<&'a $($mut)? I>,
+        }
+
+        impl<'a, I, const W: usize> $Col<'a, I, W> {
+            fn item(&self, y: usize) -> &'a $($mut)? I {
+                // SAFETY: users guaranty index is in bounds at construction,
+                // and each item is yielded once
+                debug_assert!(self.x < W, "Index out of bounds");
+                unsafe { &$($mut)? *self.items.add(y * W + self.x) }
+            }
+        }
+
+        impl<'a, I, const W: usize> Iterator for $Col<'a, I, W> {
+            type Item = &'a $($mut)? I;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let y = self.range.next()?;
+
+                Some(self.item(y))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.range.size_hint()
+            }
+        }
+
+        impl<'a, I, const W: usize> DoubleEndedIterator for $Col<'a, I, W> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let y = self.range.next_back()?;
+
+                Some(self.item(y))
+            }
+        }
+
+        impl<'a, I, const W: usize> ExactSizeIterator for $Col<'a, I, W> {}
+
+        #[doc = concat!("Iterator over the ", $mutable, "columns of an [`ArrayGrid`](ArrayGrid).")]
+        #[derive(Debug)]
+        pub struct $Cols<'a, I, const W: usize> {
+            items:   *$Ptr I,
+            range:   Range<usize>,
+            rows:    Range<usize>,
+            phantom: PhantomData<&'a $($mut)? I>,
+        }
+
+        impl<'a, I, const W: usize> $Cols<'a, I, W> {
+            fn col(&self, x: usize) -> $Col<'a, I, W> {
+                $Col {
+                    items: self.items,
+                    x,
+                    range: self.rows.clone(),
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, I, const W: usize> Iterator for $Cols<'a, I, W> {
+            type Item = $Col<'a, I, W>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let x = self.range.next()?;
+
+                Some(self.col(x))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.range.size_hint()
+            }
+        }
+
+        impl<'a, I, const W: usize> DoubleEndedIterator for $Cols<'a, I, W> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let x = self.range.next_back()?;
+
+                Some(self.col(x))
+            }
+        }
+
+        impl<'a, I, const W: usize> ExactSizeIterator for $Cols<'a, I, W> {}
+
+        #[doc = concat!("Iterator over the ", $mutable, "rows of an [`ArrayGrid`](ArrayGrid).")]
+        #[derive(Debug)]
+        pub struct $Rows<'a, I, const W: usize> {
+            rows:  $Iter<'a, [I; W]>,
+            range: Range<usize>,
+        }
+
+        impl<'a, I, const W: usize> Iterator for $Rows<'a, I, W> {
+            type Item = &'a $($mut)? [I];
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let row = self.rows.next()?;
+
+                // SAFETY: users guaranty index is in bounds at construction
+                debug_assert!(self.range.end <= W, "Index out of bounds");
+                Some(unsafe { row.$get(self.range.clone()) })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.rows.size_hint()
+            }
+        }
+
+        impl<'a, I, const W: usize> DoubleEndedIterator for $Rows<'a, I, W> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let row = self.rows.next_back()?;
+
+                // SAFETY: users guaranty index is in bounds at construction
+                debug_assert!(self.range.end <= W, "Index out of bounds");
+                Some(unsafe { row.$get(self.range.clone()) })
+            }
+        }
+
+        impl<'a, I, const W: usize> ExactSizeIterator for $Rows<'a, I, W> {}
+    )* };
+}
+
+iter!(
+    ArrayCol ArrayCols ArrayRows Iter get_unchecked const "";
+    ArrayColMut ArrayColsMut ArrayRowsMut IterMut get_unchecked_mut mut "mutable " (mut);
+);
+
+// SAFETY: iterators behave as their references
+unsafe impl<'a, I: Sync, const W: usize> Send for ArrayCol<'a, I, W> {}
+unsafe impl<'a, I: Sync, const W: usize> Sync for ArrayCol<'a, I, W> {}
+unsafe impl<'a, I: Sync, const W: usize> Send for ArrayCols<'a, I, W> {}
+unsafe impl<'a, I: Sync, const W: usize> Sync for ArrayCols<'a, I, W> {}
+unsafe impl<'a, I: Send, const W: usize> Send for ArrayColMut<'a, I, W> {}
+unsafe impl<'a, I: Sync, const W: usize> Sync for ArrayColMut<'a, I, W> {}
+unsafe impl<'a, I: Send, const W: usize> Send for ArrayColsMut<'a, I, W> {}
+unsafe impl<'a, I: Sync, const W: usize> Sync for ArrayColsMut<'a, I, W> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{string::String, vec::Vec};
+
+    #[test]
+    fn array_grid() {
+        const BOARD: ArrayGrid<char, 3, 2> = ArrayGrid::new([['x', 'o', ' '], [' ', 'x', 'o']]);
+        let mut grid = BOARD;
+
+        assert_eq!(ArrayGrid::<char, 3, 2>::SIZE, Size { x: 3, y: 2 });
+        assert_eq!(grid.get::<1, 0>(), &'o');
+        assert_eq!((&grid).item((2, 1)), Some(&'o'));
+        assert_eq!((&grid).item((3, 1)), None);
+        assert_eq!((&grid).row((1, 1..)), Some(&[' ', 'x', 'o'][1..]));
+        assert_eq!((&grid).col((1, ..)).unwrap().rev().collect::<Vec<_>>(), [
+            &'x', &'o'
+        ]);
+        assert_eq!(
+            (&grid)
+                .cols((1.., ..))
+                .unwrap()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ox", " o"]
+        );
+        assert_eq!(
+            (&grid)
+                .cropped((..2, ..))
+                .unwrap()
+                .items(..)
+                .unwrap()
+                .collect::<String>(),
+            "xo x"
+        );
+
+        *grid.get_mut::<2, 0>() = 'x';
+        (&mut grid).col(0).unwrap().for_each(|item| *item = '-');
+        for row in (&mut grid).rows((1.., 1..)).unwrap() {
+            row.reverse();
+        }
+        assert_eq!(grid.as_ref(), &['-', 'o', 'x', '-', 'o', 'x']);
+
+        let mut cols = (&mut grid).cols((1.., ..)).unwrap();
+        let (mut first, mut last) = (cols.next().unwrap(), cols.next_back().unwrap());
+        core::mem::swap(first.next_back().unwrap(), last.next().unwrap());
+        core::mem::swap(first.next().unwrap(), last.next().unwrap());
+        assert_eq!(
+            (first.next(), last.next_back(), cols.len()),
+            (None, None, 0)
+        );
+        assert_eq!(grid.as_ref(), &['-', 'x', 'o', '-', 'x', 'o']);
+        assert_eq!(BOARD.into_inner()[0], ['x', 'o', ' ']);
+    }
+
+    #[test]
+    fn from_fn() {
+        let grid = ArrayGrid::<_, 2, 3>::from_fn(|Point { x, y }| 10 * y + x);
+
+        assert_eq!(grid.as_ref(), &[0, 1, 10, 11, 20, 21]);
+        assert_eq!(
+            (&grid)
+                .rows(..)
+                .unwrap()
+                .map(<[_]>::len)
+                .collect::<Vec<_>>(),
+            [2; 3]
+        );
+        assert_eq!((&grid).items(..).unwrap().sum::<usize>(), 63);
+    }
+}
@@ -0,0 +1,379 @@
+use crate::*;
+use alloc::{string::String, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+/// A double-buffered cellular automaton.
+///
+/// Owns two [`RowGrid1D`](RowGrid1D)s of equal [`Size`](Size): the current
+/// generation, which the automaton [`Deref`](core::ops::Deref)s to, and the
+/// next one, computed by [`step`](Automaton::step).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Automaton<I> {
+    current:    RowGrid1D<I, Vec<I>>,
+    next:       RowGrid1D<I, Vec<I>>,
+    edges:      EdgePolicy,
+    generation: usize,
+}
+
+impl<I> Automaton<I> {
+    /// Creates a new `Automaton` with `grid` as the first generation.
+    pub fn new(grid: RowGrid1D<I, Vec<I>>, edges: EdgePolicy) -> Self
+    where
+        I: Clone,
+    {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            edges,
+            generation: 0,
+        }
+    }
+
+    /// Creates a new `Automaton` with `current` as the first generation and
+    /// `next` as the back buffer, or [`None`](core::option::Option::None) if
+    /// their sizes differ.
+    pub fn from_buffers(
+        current: RowGrid1D<I, Vec<I>>,
+        next: RowGrid1D<I, Vec<I>>,
+        edges: EdgePolicy,
+    ) -> Option<Self> {
+        if current.size() == next.size() {
+            Some(Self {
+                current,
+                next,
+                edges,
+                generation: 0,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the current generation.
+    pub fn current(&self) -> &RowGrid1D<I, Vec<I>> {
+        &self.current
+    }
+
+    /// Returns the current generation, mutably.
+    pub fn current_mut(&mut self) -> &mut RowGrid1D<I, Vec<I>> {
+        &mut self.current
+    }
+
+    /// Consumes the automaton, returning the current generation.
+    pub fn into_current(self) -> RowGrid1D<I, Vec<I>> {
+        self.current
+    }
+
+    /// Returns the [`EdgePolicy`](EdgePolicy).
+    pub fn edges(&self) -> EdgePolicy {
+        self.edges
+    }
+
+    /// Sets the [`EdgePolicy`](EdgePolicy).
+    pub fn set_edges(&mut self, edges: EdgePolicy) {
+        self.edges = edges;
+    }
+
+    /// Returns the number of steps computed so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Computes the next generation by applying `rule` to each cell and its
+    /// [`Neighbourhood`](Neighbourhood).
+    pub fn step(&mut self, mut rule: impl FnMut(&I, Neighbourhood<&I>) -> I) {
+        let size = self.current.size();
+        let edges = self.edges;
+        let current = &self.current;
+        let next = self.next.as_mut();
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let point = Point { x, y };
+
+                // SAFETY: point is in bounds
+                let cell = unsafe { current.item_unchecked(point) };
+                next[y * size.x + x] = rule(cell, Neighbourhood::of(current, point, edges));
+            }
+        }
+
+        self.swap();
+    }
+
+    fn swap(&mut self) {
+        core::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+}
+
+/// Fast path for Life-like automata.
+impl<I: LifeCell> Automaton<I> {
+    /// Computes the next generation with the Life-like `rule`.
+    pub fn step_life(&mut self, rule: LifeRule) {
+        let Size {
+            x: width,
+            y: height,
+        } = self.current.size();
+        let edges = self.edges;
+        let current = self.current.as_ref();
+        let next = self.next.as_mut();
+
+        let neighbour = |i: usize, delta: isize, len: usize| {
+            edges
+                .neighbour(Point { x: i, y: 0 }, (delta, 0), Size { x: len, y: 1 })
+                .map(|point| point.x)
+        };
+
+        for y in 0..height {
+            let rows = [neighbour(y, -1, height), Some(y), neighbour(y, 1, height)];
+
+            for x in 0..width {
+                let cols = [neighbour(x, -1, width), Some(x), neighbour(x, 1, width)];
+                let mut count = 0;
+
+                for row in rows.iter().flatten() {
+                    let row = &current[row * width..][..width];
+
+                    for col in cols.iter().flatten() {
+                        count += row[*col].is_alive() as u32;
+                    }
+                }
+
+                let alive = current[y * width + x].is_alive();
+                count -= alive as u32;
+                next[y * width + x] = I::from_alive(if alive {
+                    rule.survives(count)
+                } else {
+                    rule.is_born(count)
+                });
+            }
+        }
+
+        self.swap();
+    }
+}
+
+impl<I> Deref for Automaton<I> {
+    type Target = RowGrid1D<I, Vec<I>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.current
+    }
+}
+
+impl<I> DerefMut for Automaton<I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.current
+    }
+}
+
+/// Cells of Life-like automata.
+pub trait LifeCell: Copy {
+    /// Returns `true` if the cell is alive.
+    fn is_alive(self) -> bool;
+
+    /// Returns a live cell if `alive`, a dead cell otherwise.
+    fn from_alive(alive: bool) -> Self;
+}
+
+impl LifeCell for bool {
+    fn is_alive(self) -> bool {
+        self
+    }
+
+    fn from_alive(alive: bool) -> Self {
+        alive
+    }
+}
+
+/// `0` is dead, anything else is alive. Live cells are `1`.
+impl LifeCell for u8 {
+    fn is_alive(self) -> bool {
+        self != 0
+    }
+
+    fn from_alive(alive: bool) -> Self {
+        alive as u8
+    }
+}
+
+/// A Life-like rule, in B/S notation (e.g. `B3/S23` for Conway's Game of
+/// Life).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct LifeRule {
+    birth:    u16,
+    survival: u16,
+}
+
+impl LifeRule {
+    /// Conway's Game of Life (`B3/S23`).
+    pub const CONWAY: Self = Self {
+        birth:    1 << 3,
+        survival: 1 << 2 | 1 << 3,
+    };
+
+    /// Returns `true` if a dead cell with `count` live neighbours is born.
+    pub fn is_born(self, count: u32) -> bool {
+        count <= 8 && self.birth & 1 << count != 0
+    }
+
+    /// Returns `true` if a live cell with `count` live neighbours survives.
+    pub fn survives(self, count: u32) -> bool {
+        count <= 8 && self.survival & 1 << count != 0
+    }
+}
+
+impl FromStr for LifeRule {
+    type Err = ParseLifeRuleError;
+
+    /// Parses `B{digits}/S{digits}` (or `S{digits}/B{digits}`), case
+    /// insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseLifeRuleError(s.into());
+        let mut rule = Self::default();
+        let (mut birth, mut survival) = (false, false);
+
+        for part in s.trim().split('/') {
+            let mut chars = part.chars();
+            let (seen, mask) = match chars.next() {
+                Some('B') | Some('b') => (&mut birth, &mut rule.birth),
+                Some('S') | Some('s') => (&mut survival, &mut rule.survival),
+                _ => return Err(err()),
+            };
+
+            if *seen {
+                return Err(err());
+            }
+            *seen = true;
+
+            for c in chars {
+                match c.to_digit(10) {
+                    Some(count) if count <= 8 => *mask |= 1 << count,
+                    _ => return Err(err()),
+                }
+            }
+        }
+
+        if birth && survival {
+            Ok(rule)
+        } else {
+            Err(err())
+        }
+    }
+}
+
+impl Display for LifeRule {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let digits = |mask: u16| {
+            (0..=8)
+                .filter(|count| mask & 1 << count != 0)
+                .map(|count| char::from(b'0' + count as u8))
+                .collect::<String>()
+        };
+
+        write!(f, "B{}/S{}", digits(self.birth), digits(self.survival))
+    }
+}
+
+/// An error which can be returned when parsing a [`LifeRule`](LifeRule).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ParseLifeRuleError(String);
+
+impl Display for ParseLifeRuleError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "invalid B/S life rule `{}`", self.0)
+    }
+}
+
+impl Error for ParseLifeRuleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::string::ToString;
+
+    #[test]
+    fn life_rule() {
+        assert_eq!("B3/S23".parse(), Ok(LifeRule::CONWAY));
+        assert_eq!("s23/b3".parse(), Ok(LifeRule::CONWAY));
+        assert_eq!(LifeRule::CONWAY.to_string(), "B3/S23");
+        assert_eq!(
+            "B36/S23".parse::<LifeRule>().unwrap().to_string(),
+            "B36/S23"
+        );
+        assert!("B3".parse::<LifeRule>().is_err());
+        assert!("B9/S23".parse::<LifeRule>().is_err());
+        assert!("B3/S2/S3".parse::<LifeRule>().is_err());
+        assert!("X3/S23".parse::<LifeRule>().is_err());
+    }
+
+    #[test]
+    fn step() {
+        let blinker = bool_grid(
+            (5, 5),
+            "\
+            .....\
+            ..#..\
+            ..#..\
+            ..#..\
+            .....",
+        );
+        let expected = bool_grid(
+            (5, 5),
+            "\
+            .....\
+            .....\
+            .###.\
+            .....\
+            .....",
+        );
+
+        let mut generic = Automaton::new(blinker.clone(), EdgePolicy::Dead);
+        generic.step(|cell, neighbours| {
+            let count = neighbours.count(|neighbour| **neighbour) as u32;
+
+            if *cell {
+                LifeRule::CONWAY.survives(count)
+            } else {
+                LifeRule::CONWAY.is_born(count)
+            }
+        });
+        assert_eq!(generic.current(), &expected);
+        assert_eq!(generic.generation(), 1);
+
+        let mut life = Automaton::new(blinker.clone(), EdgePolicy::Wrap);
+        life.step_life(LifeRule::CONWAY);
+        assert_eq!(life.current(), &expected);
+        life.step_life(LifeRule::CONWAY);
+        assert_eq!(life.current(), &blinker);
+    }
+
+    #[test]
+    fn wrap() {
+        let glider = "\
+            .#....\
+            ..#...\
+            ###...\
+            ......\
+            ......\
+            ......";
+        let mut life = Automaton::new(bool_grid((6, 6), glider), EdgePolicy::Wrap);
+        let mut dead = Automaton::new(bool_grid((6, 6), glider), EdgePolicy::Dead);
+
+        for _ in 0..24 {
+            life.step_life(LifeRule::CONWAY);
+            dead.step_life(LifeRule::CONWAY);
+        }
+
+        // A glider travels one cell diagonally every 4 generations
+        assert_eq!(life.current(), &bool_grid((6, 6), glider));
+        assert_ne!(dead.current(), &bool_grid((6, 6), glider));
+        assert_eq!(life.item((1, 0)), Some(&true));
+    }
+}
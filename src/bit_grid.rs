@@ -0,0 +1,652 @@
+use crate::*;
+use alloc::{vec, vec::Vec};
+use core::{iter::Flatten, ops::Range};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A grid of `bool`s packed in `u64` words, row by row.
+///
+/// Each row starts on a new word, bit `x % 64` of word `x / 64` being the
+/// item at `x`. Bits past the end of rows are always unset.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BitGrid {
+    size:  Size,
+    words: Vec<u64>,
+}
+
+/// ### Constructors
+impl BitGrid {
+    /// Creates a new [`BitGrid`](BitGrid) of `size`, all unset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of words overflows.
+    pub fn new(size: Size) -> Self {
+        Self::try_new(size).expect("size overflows")
+    }
+
+    /// Creates a new [`BitGrid`](BitGrid) of `size`, all unset, or returns
+    /// [`GridError::Overflow`](GridError::Overflow) if the number of words
+    /// overflows.
+    pub fn try_new(size: Size) -> Result<Self, GridError> {
+        let len = size
+            .x
+            .div_ceil(BITS)
+            .checked_mul(size.y)
+            .ok_or(GridError::Overflow)?;
+
+        Ok(Self {
+            size,
+            words: vec![0; len],
+        })
+    }
+
+    /// Creates a new [`BitGrid`](BitGrid) of `size` with the items returned
+    /// by `f` for each point.
+    pub fn from_fn(size: Size, mut f: impl FnMut(Point) -> bool) -> Self {
+        let mut grid = Self::new(size);
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                if f(Point { x, y }) {
+                    grid.set_unchecked(Point { x, y }, true);
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Creates a new [`BitGrid`](BitGrid) from the items of `grid`.
+    pub fn from_grid<G>(grid: G) -> Self
+    where
+        G: GridRows,
+        G::Item: Into<bool>,
+    {
+        let mut bits = Self::new(grid.size());
+
+        // SAFETY: RangeFull is always in bounds
+        for (y, row) in unsafe { grid.rows_unchecked(..) }.into_iter().enumerate() {
+            for (x, item) in row.into_iter().enumerate() {
+                if item.into() {
+                    bits.set_unchecked(Point { x, y }, true);
+                }
+            }
+        }
+
+        bits
+    }
+}
+
+/// ### Accessors
+impl BitGrid {
+    /// Returns the number of words of a row.
+    pub fn row_words(&self) -> usize {
+        self.size.x.div_ceil(BITS)
+    }
+
+    /// Returns the underlying words.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Sets the item at `index`, or returns
+    /// [`None`](core::option::Option::None) if out of bounds.
+    pub fn set(&mut self, index: impl Index0D, value: bool) -> Option<()> {
+        let point = index.checked(self.size)?;
+
+        self.set_unchecked(point, value);
+        Some(())
+    }
+
+    /// Sets all items to `value`.
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { !0 } else { 0 });
+        self.clear_padding();
+    }
+
+    fn set_unchecked(&mut self, Point { x, y }: Point, value: bool) {
+        let index = y * self.row_words() + x / BITS;
+        let mask = 1 << (x % BITS);
+
+        if value {
+            self.words[index] |= mask;
+        } else {
+            self.words[index] &= !mask;
+        }
+    }
+
+    /// Returns the mask of the bits of the last word of rows.
+    fn last_mask(&self) -> u64 {
+        match self.size.x % BITS {
+            0 => !0,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    fn clear_padding(&mut self) {
+        let (row_words, mask) = (self.row_words(), self.last_mask());
+
+        if row_words != 0 {
+            for row in self.words.chunks_exact_mut(row_words) {
+                row[row_words - 1] &= mask;
+            }
+        }
+    }
+}
+
+/// ### Bulk operations
+impl BitGrid {
+    /// Computes `self & other`, or returns
+    /// [`None`](core::option::Option::None) if sizes differ.
+    pub fn and(&mut self, other: &Self) -> Option<()> {
+        self.zip(other, |a, b| a & b)
+    }
+
+    /// Computes `self | other`, or returns
+    /// [`None`](core::option::Option::None) if sizes differ.
+    pub fn or(&mut self, other: &Self) -> Option<()> {
+        self.zip(other, |a, b| a | b)
+    }
+
+    /// Computes `self ^ other`, or returns
+    /// [`None`](core::option::Option::None) if sizes differ.
+    pub fn xor(&mut self, other: &Self) -> Option<()> {
+        self.zip(other, |a, b| a ^ b)
+    }
+
+    /// Computes `!self`.
+    pub fn not(&mut self) {
+        self.words.iter_mut().for_each(|word| *word = !*word);
+        self.clear_padding();
+    }
+
+    /// Returns the number of set items in `index`, or
+    /// [`None`](core::option::Option::None) if out of bounds.
+    pub fn count_ones(&self, index: impl Index2D) -> Option<usize> {
+        let Rect { x, y } = index.checked(self.size)?;
+        let row_words = self.row_words();
+        let mut count = 0;
+
+        if x.start == x.end {
+            return Some(0);
+        }
+
+        let (first, last) = (x.start / BITS, (x.end - 1) / BITS);
+        let masks = |word: usize| {
+            let mut mask = !0;
+            if word == first {
+                mask &= !0 << (x.start % BITS);
+            }
+            if word == last && x.end % BITS != 0 {
+                mask &= (1 << (x.end % BITS)) - 1;
+            }
+            mask
+        };
+
+        for y in y {
+            let row = &self.words[y * row_words..][first..=last];
+
+            for (word, bits) in (first..).zip(row) {
+                count += (bits & masks(word)).count_ones() as usize;
+            }
+        }
+
+        Some(count)
+    }
+
+    /// Shifts items by `offset`, dropping items shifted out and unsetting
+    /// vacated items.
+    pub fn shift(&mut self, offset: Coord<isize>) {
+        let Size {
+            x: width,
+            y: height,
+        } = self.size;
+        let row_words = self.row_words();
+        let mut words = vec![0; self.words.len()];
+
+        for y in 0..height {
+            let src = if offset.y < 0 {
+                y.checked_add(offset.y.unsigned_abs())
+            } else {
+                y.checked_sub(offset.y.unsigned_abs())
+            };
+            let src = match src {
+                Some(src) if src < height => src,
+                _ => continue,
+            };
+
+            let src = &self.words[src * row_words..][..row_words];
+            let dst = &mut words[y * row_words..][..row_words];
+
+            if offset.x.unsigned_abs() < width {
+                shift_row(src, dst, offset.x);
+            }
+        }
+
+        self.words = words;
+        self.clear_padding();
+    }
+
+    /// Computes the next generation of the Life-like `rule`, 64 cells at a
+    /// time.
+    pub fn step_life(&mut self, rule: LifeRule, edges: EdgePolicy) {
+        let Size {
+            x: width,
+            y: height,
+        } = self.size;
+        let row_words = self.row_words();
+        let mut west = vec![0; self.words.len()];
+        let mut east = vec![0; self.words.len()];
+        let mut next = vec![0; self.words.len()];
+
+        for y in 0..height {
+            let range = y * row_words..(y + 1) * row_words;
+            let row = &self.words[range.clone()];
+
+            shift_row(row, &mut west[range.clone()], 1);
+            shift_row(row, &mut east[range.clone()], -1);
+
+            if edges == EdgePolicy::Wrap && width != 0 {
+                let last = width - 1;
+                west[range.start] |= bit(row, last);
+                east[range.start + last / BITS] |= bit(row, 0) << (last % BITS);
+            }
+        }
+
+        let births = (0..=8).filter(|&count| rule.is_born(count));
+        let survivals = (0..=8).filter(|&count| rule.survives(count));
+
+        for y in 0..height {
+            let neighbours = [-1, 0, 1].map(|delta| {
+                edges
+                    .neighbour(Point { x: 0, y }, (0, delta), Size { x: 1, y: height })
+                    .map(|point| point.y * row_words)
+            });
+
+            for word in 0..row_words {
+                let mut counts = [0; 4];
+
+                for (delta, start) in neighbours.iter().enumerate() {
+                    if let Some(start) = start {
+                        add(&mut counts, west[start + word]);
+                        add(&mut counts, east[start + word]);
+                        if delta != 1 {
+                            add(&mut counts, self.words[start + word]);
+                        }
+                    }
+                }
+
+                let alive = self.words[y * row_words + word];
+                let equals = |count: u32| {
+                    (0..4).fold(!0, |mask, i| {
+                        mask & if count & 1 << i != 0 {
+                            counts[i]
+                        } else {
+                            !counts[i]
+                        }
+                    })
+                };
+                let born = births.clone().fold(0, |mask, count| mask | equals(count));
+                let survives = survivals
+                    .clone()
+                    .fold(0, |mask, count| mask | equals(count));
+
+                next[y * row_words + word] = !alive & born | alive & survives;
+            }
+        }
+
+        self.words = next;
+        self.clear_padding();
+    }
+
+    fn zip(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Option<()> {
+        if self.size != other.size {
+            return None;
+        }
+
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, *b);
+        }
+
+        Some(())
+    }
+}
+
+/// Returns bit `x` of `row`, as the lowest bit.
+fn bit(row: &[u64], x: usize) -> u64 {
+    row[x / BITS] >> (x % BITS) & 1
+}
+
+/// Writes `src` shifted by `offset` bits (towards higher bits if positive)
+/// into `dst`.
+fn shift_row(src: &[u64], dst: &mut [u64], offset: isize) {
+    let (words, bits) = (offset.unsigned_abs() / BITS, offset.unsigned_abs() % BITS);
+    let get = |i: Option<usize>| i.and_then(|i| src.get(i)).copied().unwrap_or(0);
+
+    for (i, dst) in dst.iter_mut().enumerate() {
+        *dst = if offset >= 0 {
+            let (high, low) = (i.checked_sub(words), i.checked_sub(words + 1));
+
+            match bits {
+                0 => get(high),
+                _ => get(high) << bits | get(low) >> (BITS - bits),
+            }
+        } else {
+            let (low, high) = (Some(i + words), Some(i + words + 1));
+
+            match bits {
+                0 => get(low),
+                _ => get(low) >> bits | get(high) << (BITS - bits),
+            }
+        };
+    }
+}
+
+/// Adds `bits` to the bit-sliced `counts`.
+fn add(counts: &mut [u64; 4], mut bits: u64) {
+    for count in counts {
+        let carry = *count & bits;
+        *count ^= bits;
+        bits = carry;
+    }
+}
+
+impl WithSize for BitGrid {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl BitGrid {
+    /// Returns `len` items from `point`, `step` bits apart.
+    fn bits(&self, Point { x, y }: Point, step: usize, len: usize) -> Bits<'_> {
+        Bits {
+            words: &self.words,
+            index: y * self.row_words() * BITS + x,
+            step,
+            len,
+        }
+    }
+}
+
+impl Grid for &BitGrid {
+    type Item = bool;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Point { x, y } = index.unchecked();
+        let row = &self.words[y * self.row_words()..];
+
+        bit(row, x) != 0
+    }
+}
+
+impl<'a> GridRow for &'a BitGrid {
+    type Row = Bits<'a>;
+
+    unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+        let (y, Range { start, end }) = index.row_unchecked(self.size);
+
+        self.bits(Point { x: start, y }, 1, end - start)
+    }
+}
+
+impl<'a> GridCol for &'a BitGrid {
+    type Col = Bits<'a>;
+
+    unsafe fn col_unchecked(self, index: impl Index1D) -> Self::Col {
+        let (x, Range { start, end }) = index.col_unchecked(self.size);
+
+        self.bits(Point { x, y: start }, self.row_words() * BITS, end - start)
+    }
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident $lines:ident $range:ident $rows:literal)*) => { $(
+        impl<'a> $Trait for &'a BitGrid {
+            type $Assoc = BitLines<'a>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let index = index.unchecked(self.size);
+
+                BitLines {
+                    grid:  self,
+                    lines: index.$lines,
+                    range: index.$range,
+                    rows:  $rows,
+                }
+            }
+        }
+    )* };
+}
+
+grid2d!(
+    GridRows Rows rows_unchecked y x true
+    GridCols Cols cols_unchecked x y false
+);
+
+impl<'a> GridItems for &'a BitGrid {
+    type Items = Flatten<BitLines<'a>>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+/// Iterator over the items of a row or column of a [`BitGrid`](BitGrid).
+#[derive(Clone, Debug)]
+pub struct Bits<'a> {
+    words: &'a [u64],
+    index: usize,
+    step:  usize,
+    len:   usize,
+}
+
+impl<'a> Iterator for Bits<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            let index = self.index;
+
+            self.len -= 1;
+            if self.len != 0 {
+                self.index += self.step;
+            }
+
+            Some(bit(self.words, index) != 0)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Bits<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+
+            Some(bit(self.words, self.index + self.len * self.step) != 0)
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Bits<'a> {}
+
+/// Iterator over the rows or columns of a [`BitGrid`](BitGrid).
+#[derive(Clone, Debug)]
+pub struct BitLines<'a> {
+    grid:  &'a BitGrid,
+    lines: Range<usize>,
+    range: Range<usize>,
+    rows:  bool,
+}
+
+impl<'a> BitLines<'a> {
+    fn line(&self, i: usize) -> Bits<'a> {
+        let index = (i, self.range.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        unsafe {
+            if self.rows {
+                self.grid.row_unchecked(index)
+            } else {
+                self.grid.col_unchecked(index)
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for BitLines<'a> {
+    type Item = Bits<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.lines.next()?;
+
+        Some(self.line(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for BitLines<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let i = self.lines.next_back()?;
+
+        Some(self.line(i))
+    }
+}
+
+impl<'a> ExactSizeIterator for BitLines<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{string::String, vec::Vec};
+
+    fn grid(size: (usize, usize), items: &str) -> BitGrid {
+        BitGrid::from_grid((&bool_grid(size, items)).copied())
+    }
+
+    fn text(grid: &BitGrid) -> Vec<String> {
+        rows_text(grid, |bit| if bit { '#' } else { '.' })
+    }
+
+    #[test]
+    fn bits() {
+        let mut bits = BitGrid::new((70, 3).into());
+
+        bits.set((65, 1), true).unwrap();
+        bits.set((0, 2), true).unwrap();
+        assert_eq!(bits.set((70, 0), true), None);
+        assert_eq!(bits.row_words(), 2);
+        assert_eq!(bits.as_words(), &[0, 0, 0, 0b10, 1, 0]);
+        assert_eq!((&bits).item((65, 1)), Some(true));
+        assert_eq!((&bits).item((64, 1)), Some(false));
+        assert_eq!((&bits).col((65, ..)).unwrap().collect::<Vec<_>>(), [
+            false, true, false
+        ]);
+        assert_eq!((&bits).items(..).unwrap().filter(|&bit| bit).count(), 2);
+        assert_eq!(bits.count_ones((60..66, 1..)), Some(1));
+        assert_eq!(bits.count_ones((66.., ..)), Some(0));
+        assert_eq!(bits.count_ones((..71, ..)), None);
+        assert!((&bits)
+            .row((1, 63..))
+            .unwrap()
+            .rev()
+            .eq([false, false, false, false, true, false, false]));
+        let mut rows = (&bits).rows(..).unwrap();
+        assert_eq!(rows.next_back().unwrap().next(), Some(true));
+        let mut row = rows.next_back().unwrap();
+        assert_eq!(
+            (row.next_back(), row.nth(65), row.len()),
+            (Some(false), Some(true), 3)
+        );
+
+        bits.not();
+        assert_eq!(bits.count_ones(..), Some(208));
+        bits.fill(true);
+        assert_eq!(bits.as_words()[1], (1 << 6) - 1);
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            BitGrid::try_new((usize::MAX, 64).into()).err(),
+            Some(GridError::Overflow)
+        );
+        assert_eq!(BitGrid::try_new((65, 2).into()).unwrap().as_words(), [0; 4]);
+    }
+
+    #[test]
+    fn bulk() {
+        let mut a = grid((4, 2), "##....##");
+        let b = grid((4, 2), "#.#.#.#.");
+
+        a.and(&b).unwrap();
+        assert_eq!(text(&a), ["#...", "..#."]);
+        a.or(&b).unwrap();
+        assert_eq!(text(&a), ["#.#.", "#.#."]);
+        a.xor(&grid((4, 2), "####....")).unwrap();
+        assert_eq!(text(&a), [".#.#", "#.#."]);
+        assert_eq!(a.and(&BitGrid::new((2, 4).into())), None);
+
+        let mut wide = BitGrid::from_fn((130, 2).into(), |Point { x, y }| x == 63 + y);
+        wide.shift(Coord { x: 66, y: 1 });
+        assert_eq!(wide.count_ones(..), Some(1));
+        assert_eq!((&wide).item((129, 1)), Some(true));
+        wide.shift(Coord { x: -129, y: -1 });
+        assert_eq!((&wide).item((0, 0)), Some(true));
+        wide.shift(Coord { x: -1, y: 0 });
+        assert_eq!(wide.count_ones(..), Some(0));
+
+        let mut a = grid((4, 2), "##....##");
+        a.shift(Coord {
+            x: 0,
+            y: isize::MIN,
+        });
+        assert_eq!(text(&a), ["....", "...."]);
+        let mut a = grid((4, 2), "##....##");
+        a.shift(Coord {
+            x: isize::MIN,
+            y: isize::MAX,
+        });
+        assert_eq!(text(&a), ["....", "...."]);
+    }
+
+    #[test]
+    fn life() {
+        for &edges in &[EdgePolicy::Dead, EdgePolicy::Wrap] {
+            for &width in &[1, 5, 64, 67] {
+                let size = Size { x: width, y: 6 };
+                let noise = |Point { x, y }: Point| (x * 7 + y * 13 + x * y) % 5 < 2;
+                let mut bits = BitGrid::from_fn(size, noise);
+                let items = (0..6)
+                    .flat_map(|y| (0..width).map(move |x| noise(Point { x, y })))
+                    .collect();
+                let mut automaton = Automaton::new(RowGrid1D::new(size, items).unwrap(), edges);
+
+                for _ in 0..4 {
+                    bits.step_life(LifeRule::CONWAY, edges);
+                    automaton.step_life(LifeRule::CONWAY);
+                    assert_eq!(bits, BitGrid::from_grid(automaton.current().copied()));
+                }
+            }
+        }
+
+        let mut glider = grid((5, 5), ".#.....#..###............");
+        for _ in 0..4 {
+            glider.step_life(LifeRule::CONWAY, EdgePolicy::Dead);
+        }
+        assert_eq!(text(&glider), [".....", "..#..", "...#.", ".###.", "....."]);
+    }
+}
@@ -0,0 +1,354 @@
+//! Terminal cells and a Unicode-width-aware terminal grid.
+//!
+//! Wide graphemes (CJK, emoji) occupy two cells: the grapheme is stored in
+//! the first cell, followed by *continuation* cells with an empty grapheme.
+
+use crate::*;
+use alloc::{string::String, vec, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{BitOr, BitOrAssign, Deref},
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// A terminal color.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Color {
+    /// One of the 16 standard colors (`0..16`).
+    Ansi(u8),
+    /// One of the 256 indexed colors.
+    Indexed(u8),
+    /// A 24-bit color.
+    Rgb(u8, u8, u8),
+}
+
+/// Text attributes (bold, italic, ...), as a set of flags.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Attributes(u8);
+
+impl Attributes {
+    /// Blinking text.
+    pub const BLINK: Self = Self(1 << 4);
+    /// Bold text.
+    pub const BOLD: Self = Self(1 << 0);
+    /// Dimmed text.
+    pub const DIM: Self = Self(1 << 1);
+    /// Hidden text.
+    pub const HIDDEN: Self = Self(1 << 6);
+    /// Italic text.
+    pub const ITALIC: Self = Self(1 << 2);
+    /// No attributes.
+    pub const NONE: Self = Self(0);
+    /// Reversed foreground and background colors.
+    pub const REVERSE: Self = Self(1 << 5);
+    /// Crossed-out text.
+    pub const STRIKE: Self = Self(1 << 7);
+    /// Underlined text.
+    pub const UNDERLINE: Self = Self(1 << 3);
+
+    /// Returns `true` if all attributes of `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Sets the attributes of `other`.
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Unsets the attributes of `other`.
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+
+    /// Returns `true` if no attributes are set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for Attributes {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Attributes {
+    fn bitor_assign(&mut self, other: Self) {
+        self.insert(other);
+    }
+}
+
+/// The style of a [`Cell`](Cell).
+///
+/// [`None`](core::option::Option::None) colors are the terminal's defaults.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Style {
+    /// The foreground color.
+    pub foreground: Option<Color>,
+    /// The background color.
+    pub background: Option<Color>,
+    /// The text attributes.
+    pub attributes: Attributes,
+}
+
+/// A terminal cell: a grapheme and its style.
+///
+/// Cells with an empty grapheme are *continuations* of the wide grapheme on
+/// their left.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Cell {
+    /// The grapheme cluster, or `""` for continuations.
+    pub grapheme: String,
+    /// The style.
+    pub style:    Style,
+}
+
+impl Cell {
+    /// Creates a new `Cell`.
+    pub fn new(grapheme: impl Into<String>, style: Style) -> Self {
+        Self {
+            grapheme: grapheme.into(),
+            style,
+        }
+    }
+
+    /// Creates a blank (space) `Cell`.
+    pub fn blank(style: Style) -> Self {
+        Self::new(" ", style)
+    }
+
+    /// Creates a continuation `Cell`.
+    pub fn continuation(style: Style) -> Self {
+        Self::new("", style)
+    }
+
+    /// Returns `true` if this cell is the continuation of a wide grapheme.
+    pub fn is_continuation(&self) -> bool {
+        self.grapheme.is_empty()
+    }
+
+    /// Returns the number of columns the grapheme occupies.
+    pub fn width(&self) -> usize {
+        self.grapheme.width()
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::blank(Style::default())
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.grapheme)
+    }
+}
+
+/// A grid of terminal [`Cell`](Cell)s, aware of grapheme widths.
+///
+/// Writes keep rows consistent: a wide grapheme is always followed by its
+/// continuation cells, and partially overwritten wide graphemes are blanked.
+///
+/// Derefs to the underlying [`RowGrid1D`](RowGrid1D) for reading.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TermGrid {
+    grid: RowGrid1D<Cell, Vec<Cell>>,
+}
+
+impl TermGrid {
+    /// Creates a new `TermGrid` of `size`, filled with blank cells.
+    pub fn new(size: Size) -> Self {
+        Self {
+            grid: RowGrid1D::new_unchecked(size, vec![Cell::default(); size.x * size.y]),
+        }
+    }
+
+    /// Returns the underlying grid.
+    pub fn as_grid(&self) -> &RowGrid1D<Cell, Vec<Cell>> {
+        &self.grid
+    }
+
+    /// Consumes the `TermGrid`, returning the underlying grid.
+    pub fn into_grid(self) -> RowGrid1D<Cell, Vec<Cell>> {
+        self.grid
+    }
+
+    /// Fills the grid with blank cells of `style`.
+    pub fn clear(&mut self, style: Style) {
+        self.grid
+            .as_mut()
+            .iter_mut()
+            .for_each(|cell| *cell = Cell::blank(style));
+    }
+
+    /// Writes `s` with `style` at `at`, grapheme by grapheme.
+    ///
+    /// Zero-width graphemes are appended to the previous cell, control
+    /// characters are ignored, and writing stops at the first grapheme
+    /// that does not fit before the right edge (no wrapping).
+    ///
+    /// Returns the position after the last written cell, or
+    /// [`None`](core::option::Option::None) if `at` is out of bounds.
+    pub fn write_str(&mut self, at: impl Index0D, s: &str, style: Style) -> Option<Point> {
+        let Point { mut x, y } = at.checked(self.grid.size())?;
+        let width = self.grid.size().x;
+
+        // SAFETY: y is checked
+        let row = unsafe { (&mut self.grid).row_unchecked(y) };
+
+        for grapheme in s.graphemes(true) {
+            if grapheme.chars().any(char::is_control) {
+                continue;
+            }
+
+            match grapheme.width() {
+                0 =>
+                    if let Some(cell) = row[..x].iter_mut().rev().find(|c| !c.is_continuation()) {
+                        cell.grapheme.push_str(grapheme);
+                    },
+                w if x + w <= width => {
+                    put(row, x, w, Cell::new(grapheme, style));
+                    x += w;
+                }
+                _ => break,
+            }
+        }
+
+        Some(Point { x, y })
+    }
+
+    /// Sets the cell at `at` to `grapheme` with `style`, as in
+    /// [`write_str`](TermGrid::write_str), or returns
+    /// [`None`](core::option::Option::None) if it is empty or does not fit.
+    pub fn put(&mut self, at: impl Index0D, grapheme: &str, style: Style) -> Option<()> {
+        let Point { x, y } = at.checked(self.grid.size())?;
+        let w = grapheme.width().max(1);
+
+        if grapheme.is_empty() || x + w > self.grid.size().x {
+            return None;
+        }
+
+        // SAFETY: y is checked
+        let row = unsafe { (&mut self.grid).row_unchecked(y) };
+        put(row, x, w, Cell::new(grapheme, style));
+        Some(())
+    }
+
+    /// Returns the text of row `y` (continuations omitted), or
+    /// [`None`](core::option::Option::None) if out of bounds.
+    pub fn row_text(&self, y: usize) -> Option<String> {
+        Some(
+            self.grid
+                .row(y)?
+                .iter()
+                .map(|cell| cell.grapheme.as_str())
+                .collect(),
+        )
+    }
+}
+
+impl Deref for TermGrid {
+    type Target = RowGrid1D<Cell, Vec<Cell>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.grid
+    }
+}
+
+impl Display for TermGrid {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.grid.fmt(f)
+    }
+}
+
+/// Puts `cell` of width `w` at `x` in `row`, blanking overlapped wide
+/// graphemes.
+fn put(row: &mut [Cell], x: usize, w: usize, cell: Cell) {
+    // Overwriting the tail of a wide grapheme
+    if row[x].is_continuation() {
+        if let Some(head) = (0..x).rev().find(|i| !row[*i].is_continuation()) {
+            for cell in &mut row[head..x] {
+                *cell = Cell::blank(cell.style);
+            }
+        }
+    }
+
+    // Overwriting the head of a wide grapheme
+    for cell in row[x + w..]
+        .iter_mut()
+        .take_while(|cell| cell.is_continuation())
+    {
+        *cell = Cell::blank(cell.style);
+    }
+
+    let style = cell.style;
+    row[x] = cell;
+    for cell in &mut row[x + 1..x + w] {
+        *cell = Cell::continuation(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn texts(grid: &TermGrid) -> Vec<String> {
+        (0..grid.size().y)
+            .map(|y| grid.row_text(y).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn write_str() {
+        let style = Style::default();
+        let mut grid = TermGrid::new((6, 2).into());
+
+        assert_eq!(
+            grid.write_str((0, 0), "a世e\u{301}!", style),
+            Some(Point { x: 5, y: 0 })
+        );
+        assert!(grid.item((2, 0)).unwrap().is_continuation());
+        assert_eq!(grid.item((3, 0)).unwrap().grapheme, "e\u{301}");
+
+        // Clipping
+        assert_eq!(
+            grid.write_str((3, 1), "ab世", style),
+            Some(Point { x: 5, y: 1 })
+        );
+        assert_eq!(texts(&grid), vec!["a世e\u{301}! ", "   ab "]);
+
+        // Control characters, trailing combining mark
+        assert_eq!(
+            grid.write_str((0, 1), "x\n\u{301}", style),
+            Some(Point { x: 1, y: 1 })
+        );
+        assert_eq!(grid.item((0, 1)).unwrap().grapheme, "x\u{301}");
+
+        assert_eq!(grid.write_str((6, 0), "a", style), None);
+    }
+
+    #[test]
+    fn overwrite() {
+        let style = Style::default();
+        let mut grid = TermGrid::new((6, 1).into());
+
+        grid.write_str((0, 0), "世界!", style);
+        assert_eq!(texts(&grid), vec!["世界! "]);
+
+        // Tail of 世, head of 界
+        grid.write_str((1, 0), "ab", style);
+        assert_eq!(texts(&grid), vec![" ab ! "]);
+
+        grid.write_str((0, 0), "界", style);
+        grid.put((1, 0), "字", style);
+        assert_eq!(texts(&grid), vec![" 字 ! "]);
+        assert!(grid.item((2, 0)).unwrap().is_continuation());
+        assert_eq!(grid.put((5, 0), "字", style), None);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::*;
-use std::{
+use core::{
     iter::{Cloned as StdCloned, Copied as StdCopied, Map},
     ops::{Deref, DerefMut},
 };
@@ -37,6 +37,7 @@ macro_rules! cloned {
         $Type:ident: $Clone:ident ($Std:ident $cloned:ident)
     )*) => { $(
         $(#[$meta])*
+        #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
         pub struct $Type<T>(pub(crate) T);
 
         impl<T> Deref for $Type<T> {
@@ -82,11 +83,11 @@ macro_rules! cloned {
 }
 
 cloned!(
-    /// A grid yielding [`Clone`](std::clone::Clone)d items.
+    /// A grid yielding [`Clone`](core::clone::Clone)d items.
     ///
     /// This `struct` is created by [`Grid::cloned`](Grid::cloned).
     Cloned: Clone (StdCloned cloned)
-    /// A grid yielding [`Copy`](std::marker::Copy)d items.
+    /// A grid yielding [`Copy`](core::marker::Copy)d items.
     ///
     /// This `struct` is created by [`Grid::copied`](Grid::copied).
     Copied: Copy (StdCopied copied)
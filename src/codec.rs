@@ -0,0 +1,452 @@
+//! A compact binary format for [`Grid1D`](crate::Grid1D)s.
+//!
+//! A header (magic number, version, flags, size) is followed by the items,
+//! line by line in memory order (rows for row-major grids, columns for
+//! column-major grids). Lines may be delta encoded (each item as its
+//! difference to the previous item) and/or run-length encoded (runs of equal
+//! items as a count and an item).
+//!
+//! Integers are little-endian, counts are LEB128 varints.
+
+use crate::*;
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::{self, Read, Write},
+    vec::Vec,
+};
+
+/// The magic number.
+const MAGIC: [u8; 4] = *b"GRID";
+
+/// The current format version.
+pub const VERSION: u8 = 1;
+
+const RLE: u8 = 1 << 0;
+const DELTA: u8 = 1 << 1;
+const COL_MAJOR: u8 = 1 << 2;
+
+/// Items that can be encoded.
+pub trait Encode {
+    /// Writes `self` to `writer`.
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Items that can be decoded.
+pub trait Decode: Sized {
+    /// Reads a `Self` from `reader`.
+    fn decode<R: Read>(reader: &mut R) -> io::Result<Self>;
+}
+
+/// Items that can be delta encoded.
+pub trait Delta: Copy + Default {
+    /// Returns the difference from `prev` to `self`.
+    fn delta(self, prev: Self) -> Self;
+
+    /// Returns the item which differs by `self` from `prev`.
+    fn undelta(self, prev: Self) -> Self;
+}
+
+macro_rules! ints {
+    ($($T:ident)*) => { $(
+        impl Encode for $T {
+            fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+        }
+
+        impl Decode for $T {
+            fn decode<R: Read>(reader: &mut R) -> io::Result<Self> {
+                let mut bytes = [0; std::mem::size_of::<$T>()];
+                reader.read_exact(&mut bytes)?;
+
+                Ok(Self::from_le_bytes(bytes))
+            }
+        }
+
+        impl Delta for $T {
+            fn delta(self, prev: Self) -> Self {
+                self.wrapping_sub(prev)
+            }
+
+            fn undelta(self, prev: Self) -> Self {
+                self.wrapping_add(prev)
+            }
+        }
+    )* };
+}
+
+ints!(u8 u16 u32 u64 u128 i8 i16 i32 i64 i128);
+
+impl<T: Encode + ?Sized> Encode for &T {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (**self).encode(writer)
+    }
+}
+
+impl Encode for bool {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&[*self as u8])
+    }
+}
+
+impl Decode for bool {
+    fn decode<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match u8::decode(reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidData, "invalid bool")),
+        }
+    }
+}
+
+/// An error which can be returned when decoding a grid.
+#[derive(Debug)]
+pub enum CodecError {
+    /// An I/O error, or an item failed to decode.
+    Io(io::Error),
+    /// The magic number is wrong.
+    Magic,
+    /// The version is not supported.
+    Version(u8),
+    /// The flags are unknown.
+    Flags(u8),
+    /// The grid is delta encoded, but decoded without
+    /// [`decode_delta`](decode_delta).
+    Delta,
+    /// The size overflows, or a run overflows its line.
+    Corrupt,
+}
+
+impl Display for CodecError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::Magic => f.write_str("not an encoded grid"),
+            Self::Version(version) => write!(f, "unsupported version {}", version),
+            Self::Flags(flags) => write!(f, "unknown flags {:#010b}", flags),
+            Self::Delta => f.write_str("delta encoded grid, use decode_delta"),
+            Self::Corrupt => f.write_str("corrupt grid"),
+        }
+    }
+}
+
+impl Error for CodecError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Encodes `grid` to `writer`, run-length encoding lines if `rle`.
+pub fn encode<M, I, T, W>(writer: W, grid: &Grid1D<M, I, T>, rle: bool) -> io::Result<()>
+where
+    M: Major,
+    I: Encode + PartialEq,
+    T: AsRef<[I]>,
+    W: Write,
+{
+    let lines = lines(grid).map(<[I]>::iter);
+
+    encode_lines::<M, _, _>(writer, grid.size(), rle, false, lines)
+}
+
+/// Encodes `grid` to `writer` with delta encoding, run-length encoding lines
+/// if `rle`.
+pub fn encode_delta<M, I, T, W>(writer: W, grid: &Grid1D<M, I, T>, rle: bool) -> io::Result<()>
+where
+    M: Major,
+    I: Encode + PartialEq + Delta,
+    T: AsRef<[I]>,
+    W: Write,
+{
+    let lines = lines(grid).map(|line| {
+        let mut prev = I::default();
+
+        line.iter()
+            .map(move |&item| item.delta(std::mem::replace(&mut prev, item)))
+    });
+
+    encode_lines::<M, _, _>(writer, grid.size(), rle, true, lines)
+}
+
+/// Decodes a grid from `reader`, which is not delta encoded.
+///
+/// Grids encoded with another [`Major`](crate::Major) are transposed.
+pub fn decode<M, I, R>(reader: R) -> Result<Grid1D<M, I, Vec<I>>, CodecError>
+where
+    M: Major,
+    I: Decode + Clone,
+    R: Read,
+{
+    decode_lines(reader, |_| Err(CodecError::Delta))
+}
+
+/// Decodes a grid from `reader`, which may be delta encoded.
+///
+/// Grids encoded with another [`Major`](crate::Major) are transposed.
+pub fn decode_delta<M, I, R>(reader: R) -> Result<Grid1D<M, I, Vec<I>>, CodecError>
+where
+    M: Major,
+    I: Decode + Delta,
+    R: Read,
+{
+    decode_lines(reader, |line: &mut [I]| {
+        let mut prev = I::default();
+
+        line.iter_mut().for_each(|item| {
+            *item = item.undelta(prev);
+            prev = *item;
+        });
+        Ok(())
+    })
+}
+
+/// Returns the lines of `grid`, in memory order.
+fn lines<M: Major, I, T: AsRef<[I]>>(grid: &Grid1D<M, I, T>) -> std::slice::Chunks<'_, I> {
+    grid.as_ref().chunks(grid.msize().major().max(1))
+}
+
+fn encode_lines<M, W, L>(
+    writer: W,
+    size: Size,
+    rle: bool,
+    delta: bool,
+    lines: impl IntoIterator<Item = L>,
+) -> io::Result<()>
+where
+    M: Major,
+    W: Write,
+    L: IntoIterator,
+    L::Item: Encode + PartialEq,
+{
+    let mut writer = io::BufWriter::new(writer);
+    let mut flags = 0;
+    if rle {
+        flags |= RLE;
+    }
+    if delta {
+        flags |= DELTA;
+    }
+    if !is_row_major::<M>() {
+        flags |= COL_MAJOR;
+    }
+
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&[VERSION, flags])?;
+    (size.x as u64).encode(&mut writer)?;
+    (size.y as u64).encode(&mut writer)?;
+
+    for line in lines {
+        if rle {
+            let mut items = line.into_iter().peekable();
+
+            while let Some(item) = items.next() {
+                let mut run = 1;
+                while items.next_if(|next| *next == item).is_some() {
+                    run += 1;
+                }
+
+                write_varint(&mut writer, run)?;
+                item.encode(&mut writer)?;
+            }
+        } else {
+            for item in line {
+                item.encode(&mut writer)?;
+            }
+        }
+    }
+
+    writer.flush()
+}
+
+fn decode_lines<M, I, R>(
+    mut reader: R,
+    mut undelta: impl FnMut(&mut [I]) -> Result<(), CodecError>,
+) -> Result<Grid1D<M, I, Vec<I>>, CodecError>
+where
+    M: Major,
+    I: Decode + Clone,
+    R: Read,
+{
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(CodecError::Magic);
+    }
+
+    let version = u8::decode(&mut reader)?;
+    if version != VERSION {
+        return Err(CodecError::Version(version));
+    }
+
+    let flags = u8::decode(&mut reader)?;
+    if flags & !(RLE | DELTA | COL_MAJOR) != 0 {
+        return Err(CodecError::Flags(flags));
+    }
+
+    let x = usize::try_from(u64::decode(&mut reader)?).map_err(|_| CodecError::Corrupt)?;
+    let y = usize::try_from(u64::decode(&mut reader)?).map_err(|_| CodecError::Corrupt)?;
+    let len = x.checked_mul(y).ok_or(CodecError::Corrupt)?;
+    let (major, minor) = if flags & COL_MAJOR == 0 {
+        (x, y)
+    } else {
+        (y, x)
+    };
+
+    // Do not trust the header for allocations
+    let mut items = Vec::with_capacity(len.min(1 << 16));
+    let mut line = Vec::with_capacity(major.min(1 << 16));
+
+    for _ in 0..if major == 0 { 0 } else { minor } {
+        line.clear();
+
+        while line.len() < major {
+            if flags & RLE == 0 {
+                line.push(I::decode(&mut reader)?);
+            } else {
+                let run = read_varint(&mut reader)?;
+                if run == 0 || run > (major - line.len()) as u64 {
+                    return Err(CodecError::Corrupt);
+                }
+
+                let item = I::decode(&mut reader)?;
+                line.extend(core::iter::repeat_n(item, run as usize));
+            }
+        }
+
+        if flags & DELTA != 0 {
+            undelta(&mut line)?;
+        }
+        items.append(&mut line);
+    }
+
+    let size = Size { x, y };
+    if (flags & COL_MAJOR == 0) != is_row_major::<M>() {
+        items = transpose(items, major, minor);
+    }
+
+    Ok(Grid1D::new_unchecked(size, items))
+}
+
+/// Transposes `minor` lines of `major` items.
+fn transpose<I>(items: Vec<I>, major: usize, minor: usize) -> Vec<I> {
+    let mut transposed = Vec::with_capacity(items.len());
+    let mut items = items.into_iter();
+    let mut lines = (0..minor)
+        .map(|_| items.by_ref().take(major).collect::<Vec<_>>().into_iter())
+        .collect::<Vec<_>>();
+
+    for _ in 0..major {
+        transposed.extend(lines.iter_mut().filter_map(Iterator::next));
+    }
+
+    transposed
+}
+
+fn write_varint(writer: &mut impl Write, mut n: u64) -> io::Result<()> {
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+
+        if n == 0 {
+            return writer.write_all(&[byte]);
+        }
+        writer.write_all(&[byte | 0x80])?;
+    }
+}
+
+fn read_varint(reader: &mut impl Read) -> Result<u64, CodecError> {
+    let mut n = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let byte = u8::decode(reader)?;
+        n |= ((byte & 0x7f) as u64) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(n);
+        }
+    }
+
+    Err(CodecError::Corrupt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn round_trips() {
+        let items = vec![5u16, 5, 5, 6, 7, 8, 0, 0, 0, 0, 0, 1];
+        let grid = RowGrid1D::new((4, 3).into(), items).unwrap();
+
+        for &rle in &[false, true] {
+            let mut bytes = Vec::new();
+            encode(&mut bytes, &grid, rle).unwrap();
+            assert_eq!(decode::<RowMajor, u16, _>(&bytes[..]).unwrap(), grid);
+
+            let mut bytes = Vec::new();
+            encode_delta(&mut bytes, &grid, rle).unwrap();
+            assert_eq!(decode_delta::<RowMajor, u16, _>(&bytes[..]).unwrap(), grid);
+            assert!(matches!(
+                decode::<RowMajor, u16, _>(&bytes[..]),
+                Err(CodecError::Delta)
+            ));
+        }
+
+        // Transposed
+        let mut bytes = Vec::new();
+        encode(&mut bytes, &grid, true).unwrap();
+        let transposed = decode::<ColMajor, u16, _>(&bytes[..]).unwrap();
+        assert_eq!(transposed.size(), grid.size());
+        assert_eq!(
+            transposed.as_ref(),
+            &[5, 7, 0, 5, 8, 0, 5, 0, 0, 6, 0, 1][..]
+        );
+    }
+
+    #[test]
+    fn format() {
+        let grid = ColGrid1D::new((1, 300).into(), vec![true; 300]).unwrap();
+        let mut bytes = Vec::new();
+
+        encode(&mut bytes, &grid, true).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(bytes, vec![
+            b'G', b'R', b'I', b'D', VERSION, RLE | COL_MAJOR,
+            1, 0, 0, 0, 0, 0, 0, 0,
+            44, 1, 0, 0, 0, 0, 0, 0,
+            0xac, 0x02, 1,
+        ]);
+
+        let mut corrupt = bytes.clone();
+        corrupt[23] = 3;
+        assert!(matches!(
+            decode::<ColMajor, bool, _>(&corrupt[..]),
+            Err(CodecError::Corrupt)
+        ));
+
+        let mut corrupt = bytes.clone();
+        corrupt[4] = 2;
+        assert!(matches!(
+            decode::<ColMajor, bool, _>(&corrupt[..]),
+            Err(CodecError::Version(2))
+        ));
+
+        assert!(matches!(
+            decode::<ColMajor, bool, _>(&bytes[..bytes.len() - 1]),
+            Err(CodecError::Io(_))
+        ));
+    }
+}
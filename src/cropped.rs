@@ -3,6 +3,7 @@ use crate::*;
 /// A grid that only iterates over a [`Rect`](Rect).
 ///
 /// This `struct` is created by [`Grid::cropped`](Grid::cropped).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Cropped<T> {
     rect: Rect,
     grid: T,
@@ -15,11 +16,21 @@ impl<T: WithSize> Cropped<T> {
         Some(Self { rect, grid })
     }
 
+    pub(crate) fn try_new(rect: impl Index2D, grid: T) -> Result<Self, GridError> {
+        let rect = rect.try_checked(grid.size())?;
+
+        Ok(Self { rect, grid })
+    }
+
     pub(crate) unsafe fn new_unchecked(rect: impl Index2D, grid: T) -> Self {
         let rect = rect.unchecked(grid.size());
 
         Self { rect, grid }
     }
+
+    pub(crate) fn into_parts(self) -> (Rect, T) {
+        (self.rect, self.grid)
+    }
 }
 
 impl<I> WithSize for Cropped<I> {
@@ -54,6 +65,7 @@ macro_rules! grid1d {
                 let mut index = index.$fn(self.size());
                 index.0 += self.rect.$i.start;
                 index.1.start += self.rect.$range.start;
+                index.1.end += self.rect.$range.start;
 
                 self.grid.$fn(index)
             }
@@ -69,7 +81,9 @@ macro_rules! grid2d {
             unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
                 let mut index = index.unchecked(self.size());
                 index.x.start += self.rect.x.start;
+                index.x.end += self.rect.x.start;
                 index.y.start += self.rect.y.start;
+                index.y.end += self.rect.y.start;
 
                 self.grid.$fn(index)
             }
@@ -87,3 +101,28 @@ grid2d!(
     GridRows Rows rows_unchecked
     GridItems Items items_unchecked
 );
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn ranged() {
+        let grid = RowGrid1D::new((4, 3).into(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]).unwrap();
+
+        // Ranges starting after 0, on the grid
+        assert_eq!(grid.row((1, 2..4)).unwrap(), [6, 7]);
+        assert!(grid.col((1, 1..3)).unwrap().copied().eq([5, 9]));
+
+        // Ranges starting after 0, on a cropped grid
+        let cropped = (&grid).cropped((1.., 1..)).unwrap();
+        assert_eq!(cropped.clone().row((0, 1..3)).unwrap(), [6, 7]);
+        assert!(cropped.clone().col((1, 1..)).unwrap().copied().eq([10]));
+        assert!(cropped
+            .rows((1..3, 1..))
+            .unwrap()
+            .map(|row| row.iter().sum::<i32>())
+            .eq([21]));
+    }
+}
@@ -0,0 +1,374 @@
+use crate::*;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::{self, Read, Write},
+    str::FromStr,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// How to handle records with different numbers of fields when reading CSV.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CsvRagged {
+    /// All records must have the same number of fields.
+    Error,
+    /// Short records are padded with empty fields to the longest record.
+    Pad,
+    /// Long records are truncated to the shortest record.
+    Truncate,
+}
+
+/// An error which can be returned when reading CSV.
+#[derive(Debug)]
+pub enum CsvError {
+    /// An I/O error.
+    Io(io::Error),
+    /// A quoted field is not terminated, or is followed by other characters,
+    /// or a quote appears in an unquoted field.
+    Quote {
+        /// The index of the record.
+        record: usize,
+    },
+    /// A record has a different number of fields than the first, in
+    /// [`Error`](CsvRagged::Error) mode.
+    Ragged {
+        /// The index of the record.
+        record:   usize,
+        /// The expected number of fields (of the first record).
+        expected: usize,
+        /// The actual number of fields.
+        actual:   usize,
+    },
+    /// A field cannot be parsed.
+    Parse {
+        /// The index of the record.
+        record:  usize,
+        /// The index of the field.
+        field:   usize,
+        /// The parsing error message.
+        message: String,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::Quote { record } => write!(f, "record {} has misplaced quotes", record),
+            Self::Ragged {
+                record,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "record {} has {} fields, expected {}",
+                record, actual, expected
+            ),
+            Self::Parse {
+                record,
+                field,
+                message,
+            } => write!(f, "field {} of record {}: {}", field, record, message),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Reads CSV (RFC 4180) fields separated by `delimiter`, records being rows,
+/// in [`Error`](CsvRagged::Error) mode.
+pub fn read_csv(
+    reader: impl Read,
+    delimiter: char,
+) -> Result<RowGrid1D<String, Vec<String>>, CsvError> {
+    read_csv_with(reader, delimiter, CsvRagged::Error)
+}
+
+/// Reads CSV (RFC 4180) fields separated by `delimiter`, records being rows,
+/// handling records of different lengths with `ragged`.
+///
+/// Records end with `\n` or `\r\n`, and fields may be quoted with `"`
+/// (quotes being escaped as `""`) to contain delimiters and line breaks.
+pub fn read_csv_with(
+    mut reader: impl Read,
+    delimiter: char,
+    ragged: CsvRagged,
+) -> Result<RowGrid1D<String, Vec<String>>, CsvError> {
+    let mut s = String::new();
+    reader.read_to_string(&mut s)?;
+
+    let mut records = records(&s, delimiter)?;
+    let widths = records.iter().map(Vec::len);
+
+    let x = match ragged {
+        CsvRagged::Error => {
+            let expected = widths.clone().next().unwrap_or(0);
+
+            if let Some((record, actual)) = widths.enumerate().find(|(_, width)| *width != expected)
+            {
+                return Err(CsvError::Ragged {
+                    record,
+                    expected,
+                    actual,
+                });
+            }
+
+            expected
+        }
+        CsvRagged::Pad => widths.max().unwrap_or(0),
+        CsvRagged::Truncate => widths.min().unwrap_or(0),
+    };
+
+    let y = records.len();
+    let mut items = Vec::with_capacity(x * y);
+    for record in &mut records {
+        record.resize_with(x, String::new);
+        items.append(record);
+    }
+
+    Ok(RowGrid1D::new_unchecked(Size { x, y }, items))
+}
+
+/// Reads CSV as [`read_csv_with`](read_csv_with), parsing fields with
+/// [`FromStr`](core::str::FromStr).
+///
+/// Fields padded in [`Pad`](CsvRagged::Pad) mode are empty strings.
+pub fn parse_csv<I>(
+    reader: impl Read,
+    delimiter: char,
+    ragged: CsvRagged,
+) -> Result<RowGrid1D<I, Vec<I>>, CsvError>
+where
+    I: FromStr,
+    I::Err: Display,
+{
+    let grid = read_csv_with(reader, delimiter, ragged)?;
+    let size = grid.size();
+    let items = grid
+        .as_ref()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            field.parse().map_err(|err: I::Err| CsvError::Parse {
+                record:  i / size.x,
+                field:   i % size.x,
+                message: err.to_string(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(RowGrid1D::new_unchecked(size, items))
+}
+
+/// Writes `grid` as CSV (RFC 4180) with `delimiter`, rows being records.
+///
+/// Fields containing the delimiter, quotes or line breaks are quoted.
+/// Records end with `\r\n`.
+pub fn write_csv<G>(writer: impl Write, grid: G, delimiter: char) -> io::Result<()>
+where
+    G: GridRows,
+    G::Item: Display,
+{
+    // SAFETY: RangeFull is always in bounds
+    write_records(writer, unsafe { grid.rows_unchecked(..) }, delimiter)
+}
+
+/// Writes `grid` as CSV (RFC 4180) with `delimiter`, columns being records
+/// (i.e. transposed), as [`write_csv`](write_csv).
+pub fn write_csv_transposed<G>(writer: impl Write, grid: G, delimiter: char) -> io::Result<()>
+where
+    G: GridCols,
+    G::Item: Display,
+{
+    // SAFETY: RangeFull is always in bounds
+    write_records(writer, unsafe { grid.cols_unchecked(..) }, delimiter)
+}
+
+fn write_records<R>(writer: impl Write, records: R, delimiter: char) -> io::Result<()>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: Display,
+{
+    let mut writer = io::BufWriter::new(writer);
+    let mut field = String::new();
+
+    for record in records {
+        for (i, item) in record.into_iter().enumerate() {
+            if i != 0 {
+                write!(writer, "{}", delimiter)?;
+            }
+
+            field.clear();
+            fmt::Write::write_fmt(&mut field, format_args!("{}", item))
+                .expect("a Display implementation returned an error unexpectedly");
+
+            if field.contains([delimiter, '"', '\n', '\r']) {
+                write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+            } else {
+                writer.write_all(field.as_bytes())?;
+            }
+        }
+
+        writer.write_all(b"\r\n")?;
+    }
+
+    writer.flush()
+}
+
+/// Splits `s` into records of fields.
+fn records(s: &str, delimiter: char) -> Result<Vec<Vec<String>>, CsvError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut closed = false;
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let err = || CsvError::Quote {
+            record: records.len(),
+        };
+
+        if quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                    closed = true;
+                }
+            } else {
+                field.push(c);
+            }
+
+            continue;
+        }
+
+        match c {
+            c if c == delimiter => {
+                record.push(std::mem::take(&mut field));
+                closed = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+                closed = false;
+            }
+            _ if closed => return Err(err()),
+            '"' if field.is_empty() => quoted = true,
+            '"' => return Err(err()),
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(CsvError::Quote {
+            record: records.len(),
+        });
+    }
+    if closed || !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{string::String, vec, vec::Vec};
+
+    #[test]
+    fn read() {
+        let csv = "a,\"b,\"\"c\"\"\"\r\n\"multi\nline\",\n";
+        let grid = read_csv(csv.as_bytes(), ',').unwrap();
+
+        assert_eq!(grid.size(), Size { x: 2, y: 2 });
+        assert_eq!(grid.as_ref(), &["a", "b,\"c\"", "multi\nline", ""]);
+
+        let tsv = "1\t2\t3\n4\n5\t6";
+        assert!(matches!(
+            read_csv(tsv.as_bytes(), '\t'),
+            Err(CsvError::Ragged {
+                record:   1,
+                expected: 3,
+                actual:   1,
+            })
+        ));
+        assert_eq!(
+            read_csv_with(tsv.as_bytes(), '\t', CsvRagged::Pad)
+                .unwrap()
+                .as_ref(),
+            &["1", "2", "3", "4", "", "", "5", "6", ""]
+        );
+        assert_eq!(
+            parse_csv::<u8>(tsv.as_bytes(), '\t', CsvRagged::Truncate)
+                .unwrap()
+                .as_ref(),
+            &[1, 4, 5]
+        );
+
+        assert!(matches!(
+            parse_csv::<u8>("1,x".as_bytes(), ',', CsvRagged::Error),
+            Err(CsvError::Parse {
+                record: 0,
+                field: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            read_csv("a\n\"b\"c".as_bytes(), ','),
+            Err(CsvError::Quote { record: 1 })
+        ));
+        assert!(matches!(
+            read_csv("\"a".as_bytes(), ','),
+            Err(CsvError::Quote { record: 0 })
+        ));
+        assert_eq!(read_csv("".as_bytes(), ',').unwrap().size(), Size {
+            x: 0,
+            y: 0,
+        });
+    }
+
+    #[test]
+    fn write() {
+        let grid = RowGrid1D::new((2, 2).into(), vec!["a", "b;c", "\"d\"", "e\nf"]).unwrap();
+        let mut csv = Vec::new();
+
+        write_csv(&mut csv, &grid, ';').unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv, "a;\"b;c\"\r\n\"\"\"d\"\"\";\"e\nf\"\r\n");
+        assert_eq!(
+            read_csv(csv.as_bytes(), ';').unwrap().as_ref(),
+            grid.as_ref()
+        );
+
+        let mut csv = Vec::new();
+        write_csv_transposed(
+            &mut csv,
+            &RowGrid1D::new((2, 2).into(), vec![1, 2, 3, 4]).unwrap(),
+            ',',
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "1,3\r\n2,4\r\n");
+    }
+}
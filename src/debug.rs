@@ -0,0 +1,248 @@
+use crate::*;
+use alloc::{format, string::ToString, vec::Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::Range,
+};
+
+/// Debug-formats grids as aligned rows, with axis labels.
+///
+/// Only the items in the [`viewport`](DebugGrid::viewport) are shown, and at
+/// most [`limit`](DebugGrid::limit) columns and rows of it: ellipses stand
+/// for hidden items.
+///
+/// This `struct` is created by
+/// [`ToDebugGrid::debug_grid`](ToDebugGrid::debug_grid).
+#[derive(Clone)]
+pub struct DebugGrid<G> {
+    grid:     G,
+    viewport: Rect,
+    limit:    Size,
+}
+
+impl<G: WithSize> DebugGrid<G> {
+    /// Sets the visible part of the grid (defaults to the whole grid), or
+    /// returns [`None`](core::option::Option::None) if out of bounds.
+    pub fn viewport(mut self, index: impl Index2D) -> Option<Self> {
+        self.viewport = index.checked(self.grid.size())?;
+        Some(self)
+    }
+
+    /// Sets the maximum numbers of columns and rows shown (defaults to
+    /// `16x16`).
+    pub fn limit(mut self, limit: Size) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl<G> Debug for DebugGrid<G>
+where
+    G: Grid + Clone,
+    G::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let size = self.grid.size();
+        let xs = visible(self.viewport.x.clone(), size.x, self.limit.x);
+        let ys = visible(self.viewport.y.clone(), size.y, self.limit.y);
+
+        let rows = ys
+            .iter()
+            .map(|y| {
+                let y = (*y)?;
+
+                Some(
+                    xs.iter()
+                        .flatten()
+                        .map(|&x| {
+                            // SAFETY: visible indices are in the viewport, which is checked
+                            let item = unsafe { self.grid.clone().item_unchecked((x, y)) };
+
+                            format!("{:?}", item)
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let label = |i: &Option<usize>, ellipsis: &'static str| match i {
+            Some(i) => i.to_string(),
+            None => ellipsis.to_string(),
+        };
+        let label_width = ys
+            .iter()
+            .map(|y| label(y, "⋮").chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut column = 0;
+        let widths = xs
+            .iter()
+            .map(|x| {
+                let width = label(x, "…").chars().count();
+
+                if x.is_none() {
+                    return width;
+                }
+
+                let width = rows
+                    .iter()
+                    .flatten()
+                    .map(|row| row[column].chars().count())
+                    .fold(width, usize::max);
+                column += 1;
+                width
+            })
+            .collect::<Vec<_>>();
+
+        // Header
+        write!(f, "{:w$} │", "", w = label_width)?;
+        for (x, width) in xs.iter().zip(&widths) {
+            write!(f, " {:>w$}", label(x, "…"), w = width)?;
+        }
+        write!(f, "\n{}┼", "─".repeat(label_width + 1))?;
+        for width in &widths {
+            f.write_str(&"─".repeat(width + 1))?;
+        }
+
+        // Rows
+        for (y, row) in ys.iter().zip(&rows) {
+            write!(f, "\n{:>w$} │", label(y, "⋮"), w = label_width)?;
+
+            let mut items = row.iter().flatten();
+            for (x, width) in xs.iter().zip(&widths) {
+                let item = match (row, x) {
+                    (None, _) => "⋮",
+                    (_, None) => "…",
+                    (Some(_), Some(_)) => items.next().expect("visible item"),
+                };
+
+                write!(f, " {:>w$}", item, w = width)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the visible indices of `range` in `0..len`, at most `limit`,
+/// [`None`](core::option::Option::None) being ellipses.
+fn visible(range: Range<usize>, len: usize, limit: usize) -> Vec<Option<usize>> {
+    let mut visible = Vec::new();
+
+    if range.start > 0 {
+        visible.push(None);
+    }
+    if range.end - range.start <= limit {
+        visible.extend(range.clone().map(Some));
+    } else {
+        let head = limit.div_ceil(2);
+        let tail = limit / 2;
+
+        visible.extend((range.start..range.start + head).map(Some));
+        visible.push(None);
+        visible.extend((range.end - tail..range.end).map(Some));
+    }
+    if range.end < len {
+        visible.push(None);
+    }
+
+    visible.dedup();
+    visible
+}
+
+/// Debug formatting of grids.
+///
+/// Implemented for all [`Grid`](Grid)s of [`Debug`](core::fmt::Debug) items.
+pub trait ToDebugGrid: Grid + Clone
+where
+    Self::Item: Debug,
+{
+    /// Returns a [`DebugGrid`](DebugGrid), to configure debug formatting.
+    fn debug_grid(self) -> DebugGrid<Self> {
+        let size = self.size();
+
+        DebugGrid {
+            grid:     self,
+            viewport: Rect {
+                x: 0..size.x,
+                y: 0..size.y,
+            },
+            limit:    Size { x: 16, y: 16 },
+        }
+    }
+}
+
+impl<G> ToDebugGrid for G
+where
+    G: Grid + Clone,
+    G::Item: Debug,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{format, string::String, vec, vec::Vec};
+
+    #[test]
+    fn debug_grid() {
+        let grid = RowGrid1D::new((3, 2).into(), vec![1, 20, 3, 4, 5, 600]).unwrap();
+
+        assert_eq!(
+            format!("{:?}", grid),
+            [
+                "  │ 0  1   2",
+                "──┼─────────",
+                "0 │ 1 20   3",
+                "1 │ 4  5 600"
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            format!("{:#?}", RowGrid1D::new((2, 1).into(), vec!['a', 'b']).unwrap()),
+            "Grid1D {\n    size: RowMajor {\n        x: 2,\n        y: 1,\n    },\n    items: [\n        'a',\n        'b',\n    ],\n}"
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let grid = ColGrid1D::new((12, 5).into(), (0..60).collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(
+            format!("{:?}", grid.debug_grid().limit((3, 2).into())),
+            [
+                "  │ 0 1 … 11",
+                "──┼─────────",
+                "0 │ 0 5 … 55",
+                "⋮ │ ⋮ ⋮ ⋮  ⋮",
+                "4 │ 4 9 … 59",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                (&grid)
+                    .debug_grid()
+                    .viewport((1..3, 4..))
+                    .unwrap()
+                    .limit((1, 1).into())
+            ),
+            ["  │ … 1 …", "──┼──────", "⋮ │ ⋮ ⋮ ⋮", "4 │ … 9 …"].join("\n")
+        );
+        assert!(grid.debug_grid().viewport((.., 6..)).is_none());
+    }
+
+    #[test]
+    fn generic() {
+        fn debug<M: Major + Debug, I: Debug, T: AsRef<[I]> + Debug>(
+            grid: &Grid1D<M, I, T>,
+        ) -> String {
+            format!("{:?}", grid)
+        }
+
+        let grid = ColGrid1D::new((2, 1).into(), [1, 2]).unwrap();
+        assert_eq!(debug(&grid), ["  │ 0 1", "──┼────", "0 │ 1 2"].join("\n"));
+    }
+}
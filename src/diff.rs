@@ -0,0 +1,246 @@
+use crate::*;
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::marker::PhantomData;
+
+/// Creates an iterator over the runs of items of `new` that differ from
+/// `old`, row by row.
+///
+/// Items are compared one by one. When rows are slices (e.g.
+/// [`RowGrid1D`](RowGrid1D)), prefer [`diff_slices`](diff_slices()), which
+/// skips unchanged rows with slice equality.
+///
+/// Items of `new` outside of `old` are changed.
+pub fn diff<O, N>(old: O, new: N) -> Diff<O::Rows, N::Rows, ItemWise>
+where
+    O: GridRows,
+    N: GridRows,
+    N::Item: PartialEq<O::Item>,
+{
+    Diff::new(old, new)
+}
+
+/// Creates an iterator over the runs of items of `new` that differ from
+/// `old`, row by row, for grids with slice rows.
+///
+/// Unchanged rows are skipped with slice equality.
+///
+/// Items of `new` outside of `old` are changed.
+pub fn diff_slices<'a, 'b, O, N, A, B>(old: O, new: N) -> Diff<O::Rows, N::Rows, SliceWise>
+where
+    O: GridRows<Row = &'a [A], Item = &'a A>,
+    N: GridRows<Row = &'b [B], Item = &'b B>,
+    A: 'a,
+    B: 'b + PartialEq<A>,
+{
+    Diff::new(old, new)
+}
+
+/// A run of changed items.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Change<T> {
+    /// The position of the first item.
+    pub at:    Point,
+    /// The new items, from left to right.
+    pub items: Vec<T>,
+}
+
+/// Compares rows by items.
+#[derive(Copy, Clone, Debug)]
+pub enum ItemWise {}
+
+/// Compares rows as slices first.
+#[derive(Copy, Clone, Debug)]
+pub enum SliceWise {}
+
+/// Strategy to skip unchanged rows.
+pub trait RowEq<O, N> {
+    /// Returns `true` if rows `old` and `new` are known to be equal.
+    fn row_eq(old: &O, new: &N) -> bool;
+}
+
+impl<O, N> RowEq<O, N> for ItemWise {
+    fn row_eq(_: &O, _: &N) -> bool {
+        false
+    }
+}
+
+impl<A, B: PartialEq<A>> RowEq<&[A], &[B]> for SliceWise {
+    fn row_eq(old: &&[A], new: &&[B]) -> bool {
+        *new == *old
+    }
+}
+
+/// An iterator over the [`Change`](Change)s between two grids.
+///
+/// This `struct` is created by [`diff`](diff()) and
+/// [`diff_slices`](diff_slices()).
+pub struct Diff<O: IntoIterator, N: IntoIterator, C>
+where
+    N::Item: IntoIterator,
+{
+    old:      O::IntoIter,
+    new:      N::IntoIter,
+    y:        usize,
+    gap:      usize,
+    changes:  VecDeque<Change<<N::Item as IntoIterator>::Item>>,
+    _phantom: PhantomData<C>,
+}
+
+impl<O, N, C> Diff<O, N, C>
+where
+    O: IntoIterator,
+    N: IntoIterator,
+    O::Item: IntoIterator,
+    N::Item: IntoIterator,
+{
+    fn new<GO, GN>(old: GO, new: GN) -> Self
+    where
+        GO: GridRows<Rows = O>,
+        GN: GridRows<Rows = N>,
+    {
+        Self {
+            // SAFETY: RangeFull is always in bounds
+            old:      unsafe { old.rows_unchecked(..) }.into_iter(),
+            new:      unsafe { new.rows_unchecked(..) }.into_iter(),
+            y:        0,
+            gap:      0,
+            changes:  VecDeque::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Coalesces runs separated by at most `gap` unchanged items (defaults
+    /// to `0`).
+    ///
+    /// Unchanged items in between are included in the
+    /// [`Change`](Change), which is often cheaper than moving the cursor.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl<O, N, C> Iterator for Diff<O, N, C>
+where
+    O: IntoIterator,
+    N: IntoIterator,
+    O::Item: IntoIterator,
+    N::Item: IntoIterator,
+    <N::Item as IntoIterator>::Item: PartialEq<<O::Item as IntoIterator>::Item>,
+    C: RowEq<O::Item, N::Item>,
+{
+    type Item = Change<<N::Item as IntoIterator>::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.changes.is_empty() {
+            let new = self.new.next()?;
+            let old = self.old.next();
+            let y = self.y;
+            self.y += 1;
+
+            if let Some(old) = &old {
+                if C::row_eq(old, &new) {
+                    continue;
+                }
+            }
+
+            let mut old = old.map(IntoIterator::into_iter);
+            let mut run = None::<Change<_>>;
+            let mut unchanged = Vec::new();
+
+            for (x, item) in new.into_iter().enumerate() {
+                let changed = match old.as_mut().and_then(Iterator::next) {
+                    Some(old) => item != old,
+                    None => true,
+                };
+
+                match (&mut run, changed) {
+                    (Some(change), true) => {
+                        change.items.append(&mut unchanged);
+                        change.items.push(item);
+                    }
+                    (None, true) =>
+                        run = Some(Change {
+                            at:    Point { x, y },
+                            items: vec![item],
+                        }),
+                    (Some(_), false) => {
+                        unchanged.push(item);
+
+                        if unchanged.len() > self.gap {
+                            self.changes.extend(run.take());
+                            unchanged.clear();
+                        }
+                    }
+                    (None, false) => {}
+                }
+            }
+
+            self.changes.extend(run);
+        }
+
+        self.changes.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    fn changes<'a>(diff: impl Iterator<Item = Change<&'a char>>) -> Vec<(usize, usize, String)> {
+        diff.map(|change| {
+            let Change { at, items } = change;
+            (at.x, at.y, items.into_iter().collect())
+        })
+        .collect()
+    }
+
+    #[test]
+    fn diff() {
+        let old = char_grid((6, 3), "abcdefghijklmnopqr");
+        let new = char_grid((6, 3), "aXcdYZghijklmnoPQR");
+
+        let expected = vec![
+            (1, 0, "X".to_string()),
+            (4, 0, "YZ".to_string()),
+            (3, 2, "PQR".to_string()),
+        ];
+        assert_eq!(changes(super::diff(&old, &new)), expected);
+        assert_eq!(changes(diff_slices(&old, &new)), expected);
+
+        assert_eq!(changes(diff_slices(&old, &new).gap(1)), expected);
+        assert_eq!(changes(diff_slices(&old, &new).gap(2)), vec![
+            (1, 0, "XcdYZ".to_string()),
+            (3, 2, "PQR".to_string()),
+        ]);
+
+        assert_eq!(changes(diff_slices(&old, &old)), vec![]);
+    }
+
+    #[test]
+    fn resized() {
+        let old = char_grid((2, 2), "abcd");
+        let new = char_grid((3, 3), "abXcdYefg");
+
+        assert_eq!(changes(super::diff(&old, &new)), vec![
+            (2, 0, "X".to_string()),
+            (2, 1, "Y".to_string()),
+            (0, 2, "efg".to_string()),
+        ]);
+
+        // Columns and cropped grids
+        let old = ColGrid1D::new((2, 2).into(), vec!['a', 'c', 'b', 'd']).unwrap();
+        let new = char_grid((3, 3), "abXcdYefg");
+
+        assert_eq!(
+            changes(super::diff(&old, new.cropped((..2, ..2)).unwrap())),
+            vec![]
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use crate::*;
+use std::{cmp::Reverse, collections::BinaryHeap, vec, vec::Vec};
+
+/// Computes a multi-source *Dijkstra map* of `grid`.
+///
+/// Each cell of the returned grid holds the cost of the cheapest path from
+/// any of the `sources` to that cell, or [`None`](core::option::Option::None)
+/// if unreachable.
+///
+/// `cost` returns the cost of entering a cell, or
+/// [`None`](core::option::Option::None) if the cell is impassable. Sources
+/// have a cost of `0`, out-of-bounds sources are ignored.
+pub fn dijkstra_map<G: GridRows>(
+    grid: G,
+    sources: impl IntoIterator<Item = impl Index0D>,
+    connectivity: Connectivity,
+    cost: impl FnMut(G::Item) -> Option<usize>,
+) -> RowGrid1D<Option<usize>, Vec<Option<usize>>> {
+    let size = grid.size();
+    let costs = collect_rows(grid, cost);
+    let mut distances = vec![None; costs.len()];
+    let mut heap = BinaryHeap::new();
+
+    for source in sources {
+        if let Some(point) = source.checked(size) {
+            let index = point.y * size.x + point.x;
+
+            distances[index] = Some(0);
+            heap.push(Reverse((0usize, point.y, point.x)));
+        }
+    }
+
+    while let Some(Reverse((distance, y, x))) = heap.pop() {
+        if distances[y * size.x + x] < Some(distance) {
+            continue;
+        }
+
+        for neighbour in connectivity.neighbours(Point { x, y }, size) {
+            let index = neighbour.y * size.x + neighbour.x;
+
+            if let Some(cost) = costs[index] {
+                let distance = distance.saturating_add(cost);
+
+                if distances[index].is_none_or(|current| distance < current) {
+                    distances[index] = Some(distance);
+                    heap.push(Reverse((distance, neighbour.y, neighbour.x)));
+                }
+            }
+        }
+    }
+
+    RowGrid1D::new_unchecked(size, distances)
+}
+
+/// Computes the exact euclidean distance transform of `grid`.
+///
+/// Each cell of the returned grid holds the euclidean distance to the
+/// nearest cell for which `is_feature` returns `true`, or
+/// [`INFINITY`](f64::INFINITY) if there is no such cell.
+///
+/// Runs in linear time (Felzenszwalb & Huttenlocher).
+pub fn euclidean_distance<G: GridRows>(
+    grid: G,
+    is_feature: impl FnMut(G::Item) -> bool,
+) -> RowGrid1D<f64, Vec<f64>> {
+    let size = grid.size();
+    let mut is_feature = is_feature;
+    let mut distances = collect_rows(
+        grid,
+        |item| {
+            if is_feature(item) {
+                0.
+            } else {
+                f64::INFINITY
+            }
+        },
+    );
+
+    let len = size.x.max(size.y);
+    let mut input = vec![0.; len];
+    let mut output = vec![0.; len];
+    let mut parabolas = Parabolas::new(len);
+
+    // Columns
+    for x in 0..size.x {
+        for y in 0..size.y {
+            input[y] = distances[y * size.x + x];
+        }
+        parabolas.transform(&input[..size.y], &mut output[..size.y]);
+        for y in 0..size.y {
+            distances[y * size.x + x] = output[y];
+        }
+    }
+
+    // Rows
+    for row in distances.chunks_mut(size.x.max(1)) {
+        parabolas.transform(row, &mut output[..row.len()]);
+        row.copy_from_slice(&output[..row.len()]);
+    }
+
+    for distance in &mut distances {
+        *distance = distance.sqrt();
+    }
+
+    RowGrid1D::new_unchecked(size, distances)
+}
+
+/// Buffers for the 1D squared distance transform of sampled functions.
+struct Parabolas {
+    vertices:   Vec<usize>,
+    boundaries: Vec<f64>,
+}
+
+impl Parabolas {
+    fn new(len: usize) -> Self {
+        Self {
+            vertices:   vec![0; len],
+            boundaries: vec![0.; len + 1],
+        }
+    }
+
+    fn transform(&mut self, f: &[f64], d: &mut [f64]) {
+        let (v, z) = (&mut self.vertices, &mut self.boundaries);
+        let mut k = None::<usize>;
+
+        // Lower envelope of the parabolas rooted at finite samples
+        for q in 0..f.len() {
+            if f[q].is_infinite() {
+                continue;
+            }
+
+            let fq = f[q] + (q * q) as f64;
+            let mut s = f64::NEG_INFINITY;
+
+            while let Some(i) = k {
+                let p = v[i];
+                let intersection = (fq - (f[p] + (p * p) as f64)) / (2 * (q - p)) as f64;
+
+                if intersection <= z[i] {
+                    k = i.checked_sub(1);
+                } else {
+                    s = intersection;
+                    break;
+                }
+            }
+
+            let i = k.map_or(0, |i| i + 1);
+            v[i] = q;
+            z[i] = s;
+            z[i + 1] = f64::INFINITY;
+            k = Some(i);
+        }
+
+        if k.is_none() {
+            d.iter_mut().for_each(|d| *d = f64::INFINITY);
+            return;
+        }
+
+        let mut i = 0;
+        for (q, d) in d.iter_mut().enumerate() {
+            while z[i + 1] < q as f64 {
+                i += 1;
+            }
+
+            let p = v[i];
+            let delta = q.abs_diff(p) as f64;
+            *d = delta * delta + f[p];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn dijkstra_map() {
+        let grid = char_grid(
+            (4, 3),
+            "\
+            ..#.\
+            ..#.\
+            ....",
+        );
+        let map = super::dijkstra_map(&grid, vec![(0, 0), (9, 9)], Connectivity::Four, |c| {
+            if *c == '#' {
+                None
+            } else {
+                Some(1)
+            }
+        });
+
+        assert_eq!(map.as_ref(), &[
+            Some(0),
+            Some(1),
+            None,
+            Some(7),
+            Some(1),
+            Some(2),
+            None,
+            Some(6),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+        ]);
+
+        let map = super::dijkstra_map(&grid, vec![(0, 0)], Connectivity::Eight, |c| {
+            if *c == '#' {
+                None
+            } else {
+                Some(1)
+            }
+        });
+
+        assert_eq!(map.item((3, 0)), Some(&Some(4)));
+    }
+
+    #[test]
+    fn euclidean_distance() {
+        let grid = char_grid(
+            (5, 3),
+            "\
+            x....\
+            .....\
+            ....x",
+        );
+        let map = super::euclidean_distance(&grid, |c| *c == 'x');
+
+        assert_eq!(*map.item((0, 0)).unwrap(), 0.);
+        assert_eq!(*map.item((1, 0)).unwrap(), 1.);
+        assert_eq!(*map.item((1, 1)).unwrap(), 2f64.sqrt());
+        assert_eq!(*map.item((2, 2)).unwrap(), 2.);
+        assert_eq!(*map.item((2, 0)).unwrap(), 2.);
+        assert_eq!(*map.item((4, 2)).unwrap(), 0.);
+
+        let map = super::euclidean_distance(&grid, |_| false);
+        assert!(map.as_ref().iter().all(|d| d.is_infinite()));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::*;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// An error which can be returned when checking indexes or constructing
+/// grids.
+///
+/// This `enum` is returned by the `try_*` methods of grids
+/// ([`Grid::try_item`](Grid::try_item), ...) and indexes
+/// ([`Index2D::try_checked`](Index2D::try_checked), ...).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum GridError {
+    /// The index is out of bounds.
+    ///
+    /// Points are reported as one item rects, columns/rows as one column/row
+    /// rects. Ranges with an unbounded end starting after `size` are empty
+    /// at their start.
+    OutOfBounds {
+        /// The index, as a [`Rect`](Rect).
+        index: Rect,
+        /// The size of the grid.
+        size:  Size,
+    },
+    /// A range starts after its end.
+    InvertedRange,
+    /// A bound (`..=usize::MAX`, `(Excluded(usize::MAX), _)`) or a size
+    /// overflows.
+    Overflow,
+    /// The buffer length does not match the size.
+    LengthMismatch {
+        /// The length required by the size.
+        expected: usize,
+        /// The length of the buffer.
+        actual:   usize,
+    },
+}
+
+impl GridError {
+    /// Swaps the `x` and `y` axis of [`OutOfBounds`](GridError::OutOfBounds).
+    pub(crate) fn transposed(self) -> Self {
+        match self {
+            Self::OutOfBounds { index, size } => Self::OutOfBounds {
+                index: Rect {
+                    x: index.y,
+                    y: index.x,
+                },
+                size:  Size {
+                    x: size.y,
+                    y: size.x,
+                },
+            },
+            err => err,
+        }
+    }
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, size } => write!(
+                f,
+                "index ({:?}, {:?}) out of bounds of size ({}, {})",
+                index.x, index.y, size.x, size.y
+            ),
+            Self::InvertedRange => f.write_str("range starts after its end"),
+            Self::Overflow => f.write_str("index or size overflows"),
+            Self::LengthMismatch { expected, actual } => {
+                write!(f, "buffer has {} items, expected {}", actual, expected)
+            }
+        }
+    }
+}
+
+impl Error for GridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::*;
+    use pretty_assertions::assert_eq;
+    use std::{string::ToString, vec};
+
+    #[test]
+    fn try_checked() {
+        let grid = RowGrid1D::try_new((3, 2).into(), [0, 1, 2, 3, 4, 5]).unwrap();
+        let size = Size { x: 3, y: 2 };
+
+        assert_eq!(grid.try_item((2, 1)), Ok(&5));
+        assert_eq!(
+            grid.try_item((1, 2)),
+            Err(GridError::OutOfBounds {
+                index: Rect { x: 1..2, y: 2..3 },
+                size,
+            })
+        );
+
+        assert_eq!(
+            grid.try_row((1, 1..)).map(|row| row.to_vec()),
+            Ok(vec![4, 5])
+        );
+        assert_eq!(
+            grid.try_row((1, 1..4)).unwrap_err(),
+            GridError::OutOfBounds {
+                index: Rect { x: 1..4, y: 1..2 },
+                size,
+            }
+        );
+        assert_eq!(grid.try_col((3, ..)).unwrap_err(), GridError::OutOfBounds {
+            index: Rect { x: 3..4, y: 0..2 },
+            size,
+        });
+        assert_eq!(
+            grid.try_col((0, (Included(2), Excluded(1)))).unwrap_err(),
+            GridError::InvertedRange
+        );
+        assert_eq!(
+            grid.try_col((0, ..=usize::MAX)).unwrap_err(),
+            GridError::Overflow
+        );
+
+        assert_eq!(grid.try_rows((.., 1..)).map(|rows| rows.count()), Ok(1));
+        assert_eq!(
+            grid.try_items((4.., ..)).err(),
+            Some(GridError::OutOfBounds {
+                index: Rect { x: 4..4, y: 0..2 },
+                size,
+            })
+        );
+        assert_eq!(
+            grid.try_cols((.., 3..)).err(),
+            Some(GridError::OutOfBounds {
+                index: Rect { x: 0..3, y: 3..3 },
+                size,
+            })
+        );
+        assert!(grid.try_cropped((1.., ..)).is_ok());
+    }
+
+    #[test]
+    fn try_new() {
+        assert_eq!(
+            RowGrid1D::try_new((3, 2).into(), [0; 5]).err(),
+            Some(GridError::LengthMismatch {
+                expected: 6,
+                actual:   5,
+            })
+        );
+        assert_eq!(
+            ColGrid1D::try_new((usize::MAX, 2).into(), [0; 5]).err(),
+            Some(GridError::Overflow)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let err = RowGrid1D::try_new((2, 2).into(), [0; 4])
+            .unwrap()
+            .try_item((2, 0))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "index (2..3, 0..1) out of bounds of size (2, 2)"
+        );
+        assert_eq!(
+            GridError::LengthMismatch {
+                expected: 6,
+                actual:   5,
+            }
+            .to_string(),
+            "buffer has 5 items, expected 6"
+        );
+    }
+}
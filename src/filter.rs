@@ -0,0 +1,497 @@
+use crate::*;
+use std::vec::Vec;
+
+/// Numeric values, as handled by [`convolve`](convolve()) and filters.
+pub trait Numeric: Copy + PartialOrd {
+    /// Converts to `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Converts from `f64`, rounding and saturating for integers.
+    fn from_f64(value: f64) -> Self;
+}
+
+/// Numeric values, or references to numeric values.
+///
+/// Grids may yield items by value or by reference: this trait gets the
+/// underlying [`Numeric`](Numeric) value.
+pub trait Sample {
+    /// The underlying [`Numeric`](Numeric) type.
+    type Value: Numeric;
+
+    /// Returns the underlying value.
+    fn value(self) -> Self::Value;
+}
+
+macro_rules! numeric {
+    (float $($T:ident)*) => { $(
+        impl Numeric for $T {
+            fn to_f64(self) -> f64 { self as f64 }
+            fn from_f64(value: f64) -> Self { value as $T }
+        }
+
+        numeric!(impl $T);
+    )* };
+    (int $($T:ident)*) => { $(
+        impl Numeric for $T {
+            fn to_f64(self) -> f64 { self as f64 }
+            fn from_f64(value: f64) -> Self { value.round() as $T }
+        }
+
+        numeric!(impl $T);
+    )* };
+    (impl $T:ident) => {
+        impl Sample for $T {
+            type Value = $T;
+            fn value(self) -> $T { self }
+        }
+
+        impl Sample for &$T {
+            type Value = $T;
+            fn value(self) -> $T { *self }
+        }
+
+        impl Sample for &mut $T {
+            type Value = $T;
+            fn value(self) -> $T { *self }
+        }
+    };
+}
+
+numeric!(float f32 f64);
+numeric!(int u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// What lies beyond the edges of a grid, when sampling out of bounds.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Border {
+    /// Zeros (`000|abcd|000`).
+    Zero,
+    /// The nearest edge item (`aaa|abcd|ddd`).
+    Clamp,
+    /// The opposite edge (`bcd|abcd|abc`).
+    Wrap,
+    /// The mirrored edge, without repeating it (`dcb|abcd|cba`).
+    Mirror,
+}
+
+impl Border {
+    /// Returns the in-bounds index for `i` on an axis of length `len`, or
+    /// [`None`](core::option::Option::None) for zeros.
+    pub fn index(self, i: isize, len: usize) -> Option<usize> {
+        let n = len as isize;
+
+        if 0 <= i && i < n {
+            return Some(i as usize);
+        } else if len == 0 {
+            return None;
+        }
+
+        match self {
+            Self::Zero => None,
+            Self::Clamp => Some(i.clamp(0, n - 1) as usize),
+            Self::Wrap => Some(i.rem_euclid(n) as usize),
+            Self::Mirror =>
+                if n == 1 {
+                    Some(0)
+                } else {
+                    let period = 2 * (n - 1);
+                    let i = i.rem_euclid(period);
+
+                    Some(if i < n { i } else { period - i } as usize)
+                },
+        }
+    }
+}
+
+/// Convolves `grid` with `kernel`, with `border`.
+///
+/// The kernel is anchored at its center (`size / 2`) and is not flipped
+/// (i.e. this is a cross-correlation, as in most image processing
+/// libraries). Separable (rank 1) kernels are detected and applied as two 1D
+/// passes.
+pub fn convolve<O, G, K>(grid: G, kernel: K, border: Border) -> RowGrid1D<O, Vec<O>>
+where
+    O: Numeric,
+    G: GridRows,
+    G::Item: Sample,
+    K: GridRows,
+    K::Item: Sample,
+{
+    let size = grid.size();
+    let ksize = kernel.size();
+    let input = collect_rows(grid, |item| item.value().to_f64());
+    let weights = collect_rows(kernel, |item| item.value().to_f64());
+
+    let output = if ksize.x > 1 && ksize.y > 1 {
+        match separate(&weights, ksize) {
+            Some((horizontal, vertical)) =>
+                convolve_separable(&input, size, &horizontal, &vertical, border),
+            None => convolve_2d(&input, size, &weights, ksize, border),
+        }
+    } else {
+        convolve_2d(&input, size, &weights, ksize, border)
+    };
+
+    RowGrid1D::new_unchecked(size, output.into_iter().map(O::from_f64).collect())
+}
+
+/// Applies a median filter over a `window` to `grid`, with `border`.
+///
+/// The window is anchored at its center (`window / 2`). For even-sized
+/// windows, the lower median is used.
+pub fn median_filter<G>(
+    grid: G,
+    window: Size,
+    border: Border,
+) -> RowGrid1D<<G::Item as Sample>::Value, Vec<<G::Item as Sample>::Value>>
+where
+    G: GridRows,
+    G::Item: Sample,
+{
+    let size = grid.size();
+    let input = collect_rows(grid, Sample::value);
+    let zero = Numeric::from_f64(0.);
+    let mut samples = Vec::with_capacity(window.x * window.y);
+    let mut output = Vec::with_capacity(input.len());
+
+    for y in 0..size.y {
+        for x in 0..size.x {
+            samples.clear();
+
+            for wy in 0..window.y {
+                let sy = border.index((y + wy) as isize - (window.y / 2) as isize, size.y);
+
+                for wx in 0..window.x {
+                    let sx = border.index((x + wx) as isize - (window.x / 2) as isize, size.x);
+
+                    samples.push(match (sx, sy) {
+                        (Some(sx), Some(sy)) => input[sy * size.x + sx],
+                        _ => zero,
+                    });
+                }
+            }
+
+            if samples.is_empty() {
+                output.push(input[y * size.x + x]);
+            } else {
+                let mid = (samples.len() - 1) / 2;
+                let (_, median, _) = samples.select_nth_unstable_by(mid, |a, b| {
+                    a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal)
+                });
+
+                output.push(*median);
+            }
+        }
+    }
+
+    RowGrid1D::new_unchecked(size, output)
+}
+
+/// Applies a minimum filter (erosion) over a `window` to `grid`, with
+/// `border`.
+///
+/// The window is anchored at its center (`window / 2`).
+pub fn min_filter<G>(
+    grid: G,
+    window: Size,
+    border: Border,
+) -> RowGrid1D<<G::Item as Sample>::Value, Vec<<G::Item as Sample>::Value>>
+where
+    G: GridRows,
+    G::Item: Sample,
+{
+    rank_filter(grid, window, border, |a, b| if b < a { b } else { a })
+}
+
+/// Applies a maximum filter (dilation) over a `window` to `grid`, with
+/// `border`.
+///
+/// The window is anchored at its center (`window / 2`).
+pub fn max_filter<G>(
+    grid: G,
+    window: Size,
+    border: Border,
+) -> RowGrid1D<<G::Item as Sample>::Value, Vec<<G::Item as Sample>::Value>>
+where
+    G: GridRows,
+    G::Item: Sample,
+{
+    rank_filter(grid, window, border, |a, b| if b > a { b } else { a })
+}
+
+/// Min/max filters are separable: filters rows, then columns.
+fn rank_filter<G, T>(
+    grid: G,
+    window: Size,
+    border: Border,
+    pick: impl Fn(T, T) -> T,
+) -> RowGrid1D<T, Vec<T>>
+where
+    G: GridRows,
+    G::Item: Sample<Value = T>,
+    T: Numeric,
+{
+    let size = grid.size();
+    let input = collect_rows(grid, Sample::value);
+    let zero = T::from_f64(0.);
+    let sample = |items: &[T], i: isize, len: usize, stride: usize, offset: usize| {
+        border
+            .index(i, len)
+            .map_or(zero, |i| items[offset + i * stride])
+    };
+
+    let pass = |input: &[T], len: usize, window: usize, stride: usize| {
+        let mut output = input.to_vec();
+
+        if window == 0 {
+            return output;
+        }
+
+        for (index, output) in output.iter_mut().enumerate() {
+            let i = (index / stride) % len;
+            let offset = index - i * stride;
+            let start = i as isize - (window / 2) as isize;
+            let mut acc = sample(input, start, len, stride, offset);
+
+            for w in 1..window {
+                acc = pick(acc, sample(input, start + w as isize, len, stride, offset));
+            }
+
+            *output = acc;
+        }
+
+        output
+    };
+
+    let rows = pass(&input, size.x, window.x, 1);
+    let output = pass(&rows, size.y, window.y, size.x);
+
+    RowGrid1D::new_unchecked(size, output)
+}
+
+fn convolve_2d(
+    input: &[f64],
+    size: Size,
+    weights: &[f64],
+    ksize: Size,
+    border: Border,
+) -> Vec<f64> {
+    let (ax, ay) = ((ksize.x / 2) as isize, (ksize.y / 2) as isize);
+    let mut output = Vec::with_capacity(input.len());
+
+    for y in 0..size.y {
+        for x in 0..size.x {
+            let mut sum = 0.;
+
+            for ky in 0..ksize.y {
+                let sy = match border.index(y as isize + ky as isize - ay, size.y) {
+                    Some(sy) => sy,
+                    None => continue,
+                };
+
+                for kx in 0..ksize.x {
+                    if let Some(sx) = border.index(x as isize + kx as isize - ax, size.x) {
+                        sum += weights[ky * ksize.x + kx] * input[sy * size.x + sx];
+                    }
+                }
+            }
+
+            output.push(sum);
+        }
+    }
+
+    output
+}
+
+fn convolve_separable(
+    input: &[f64],
+    size: Size,
+    horizontal: &[f64],
+    vertical: &[f64],
+    border: Border,
+) -> Vec<f64> {
+    let rows = convolve_2d(
+        input,
+        size,
+        horizontal,
+        Size {
+            x: horizontal.len(),
+            y: 1,
+        },
+        border,
+    );
+
+    convolve_2d(
+        &rows,
+        size,
+        vertical,
+        Size {
+            x: 1,
+            y: vertical.len(),
+        },
+        border,
+    )
+}
+
+/// Returns the horizontal and vertical factors of `weights` if it is a
+/// rank 1 matrix.
+fn separate(weights: &[f64], size: Size) -> Option<(Vec<f64>, Vec<f64>)> {
+    let (pivot, max) = weights.iter().map(|weight| weight.abs()).enumerate().fold(
+        (0, 0.),
+        |(i, max), (j, weight)| {
+            if weight > max {
+                (j, weight)
+            } else {
+                (i, max)
+            }
+        },
+    );
+
+    if max == 0. || !max.is_finite() {
+        return None;
+    }
+
+    let (px, py) = (pivot % size.x, pivot / size.x);
+    let horizontal = weights[py * size.x..][..size.x].to_vec();
+    let vertical = (0..size.y)
+        .map(|y| weights[y * size.x + px] / weights[pivot])
+        .collect::<Vec<_>>();
+    let epsilon = max * 1e-12;
+
+    for y in 0..size.y {
+        for x in 0..size.x {
+            if (vertical[y] * horizontal[x] - weights[y * size.x + x]).abs() > epsilon {
+                return None;
+            }
+        }
+    }
+
+    Some((horizontal, vertical))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn border() {
+        let indexes = |border: Border| (-3..7).map(|i| border.index(i, 4)).collect::<Vec<_>>();
+
+        assert_eq!(indexes(Border::Zero), vec![
+            None,
+            None,
+            None,
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3),
+            None,
+            None,
+            None
+        ]);
+        assert_eq!(indexes(Border::Clamp), vec![
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(3),
+            Some(3),
+            Some(3)
+        ]);
+        assert_eq!(indexes(Border::Wrap), vec![
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(0),
+            Some(1),
+            Some(2)
+        ]);
+        assert_eq!(indexes(Border::Mirror), vec![
+            Some(3),
+            Some(2),
+            Some(1),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(2),
+            Some(1),
+            Some(0)
+        ]);
+    }
+
+    #[test]
+    fn convolve() {
+        #[rustfmt::skip]
+        let impulse = RowGrid1D::new((3, 3).into(), vec![
+            0u8, 0, 0,
+            0, 9, 0,
+            0, 0, 0,
+        ]).unwrap();
+
+        let blurred: RowGrid1D<u8, _> =
+            super::convolve(&impulse, &kernel::box_blur((3, 3).into()), Border::Zero);
+        assert_eq!(blurred.as_ref(), &[1; 9]);
+
+        // Not separable
+        let edges: RowGrid1D<i32, _> =
+            super::convolve(&impulse, &kernel::laplacian(), Border::Zero);
+        assert_eq!(edges.as_ref(), &[0, 9, 0, 9, -36, 9, 0, 9, 0]);
+
+        #[rustfmt::skip]
+        let ramp = RowGrid1D::new((3, 2).into(), vec![
+            0., 1., 2.,
+            0., 1., 2.,
+        ]).unwrap();
+        let gradient: RowGrid1D<f64, _> = super::convolve(&ramp, &kernel::sobel_x(), Border::Clamp);
+        assert_eq!(gradient.as_ref(), &[4., 8., 4., 4., 8., 4.]);
+
+        let gaussian = kernel::gaussian(2, 1.);
+        let separable: RowGrid1D<f64, _> = super::convolve(&ramp, &gaussian, Border::Mirror);
+        let generic = convolve_2d(
+            ramp.as_ref(),
+            ramp.size(),
+            gaussian.as_ref(),
+            gaussian.size(),
+            Border::Mirror,
+        );
+        for (a, b) in separable.as_ref().iter().zip(&generic) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn filters() {
+        #[rustfmt::skip]
+        let grid = RowGrid1D::new((4, 3).into(), vec![
+            1, 2, 3, 4,
+            5, 9, 7, 8,
+            9, 1, 0, 2,
+        ]).unwrap();
+        let window = (3, 3).into();
+
+        assert_eq!(min_filter(&grid, window, Border::Clamp).as_ref(), &[
+            1, 1, 2, 3, //
+            1, 0, 0, 0, //
+            1, 0, 0, 0, //
+        ]);
+        assert_eq!(max_filter(&grid, window, Border::Zero).as_ref(), &[
+            9, 9, 9, 8, //
+            9, 9, 9, 8, //
+            9, 9, 9, 8, //
+        ]);
+        assert_eq!(median_filter(&grid, window, Border::Clamp).as_ref(), &[
+            2, 3, 4, 4, //
+            5, 3, 3, 4, //
+            9, 5, 2, 2, //
+        ]);
+    }
+}
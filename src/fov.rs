@@ -0,0 +1,226 @@
+use crate::*;
+use alloc::{vec, vec::Vec};
+
+/// Computes the field of view from `origin` in `grid`, using recursive
+/// shadowcasting.
+///
+/// Cells for which `opaque` returns `true` block the view, but are visible
+/// themselves. Cells further than `radius` from `origin` are not visible.
+///
+/// Returns a grid of the same size where visible cells are `true`. When
+/// `origin` is out of bounds, no cell is visible.
+pub fn field_of_view<G: GridRows>(
+    grid: G,
+    origin: impl Index0D,
+    radius: usize,
+    opaque: impl FnMut(G::Item) -> bool,
+) -> RowGrid1D<bool, Vec<bool>> {
+    let size = grid.size();
+    let opaque = collect_rows(grid, opaque);
+    let mut visible = vec![false; opaque.len()];
+
+    if let Some(origin) = origin.checked(size) {
+        let mut shadowcast = Shadowcast {
+            size,
+            origin,
+            radius: radius.min(size.x + size.y) as isize,
+            opaque: &opaque,
+            visible: &mut visible,
+        };
+
+        shadowcast.visible[origin.y * size.x + origin.x] = true;
+        for octant in &OCTANTS {
+            shadowcast.cast(1, 1., 0., *octant);
+        }
+    }
+
+    RowGrid1D::new_unchecked(size, visible)
+}
+
+/// Returns `true` if `to` can be seen from `from` in `grid`, `false`
+/// otherwise.
+///
+/// Walks the [`line`](line()) from `from` to `to`: cells in between must not
+/// be `opaque` (endpoints may be). Returns `false` if the line goes out of
+/// bounds.
+pub fn line_of_sight<G: Grid + Clone>(
+    grid: G,
+    from: Point,
+    to: Point,
+    mut opaque: impl FnMut(G::Item) -> bool,
+) -> bool {
+    let size = grid.size();
+
+    if !(from < size && to < size) {
+        return false;
+    }
+
+    let mut line = line(from, to);
+    line.next();
+
+    line.take_while(|point| *point != to)
+        .all(|point| grid.clone().item(point).is_some_and(|item| !opaque(item)))
+}
+
+/// Octant transformations `(xx, xy, yx, yy)`.
+const OCTANTS: [(isize, isize, isize, isize); 8] = [
+    (1, 0, 0, 1),
+    (0, 1, 1, 0),
+    (0, -1, 1, 0),
+    (-1, 0, 0, 1),
+    (-1, 0, 0, -1),
+    (0, -1, -1, 0),
+    (0, 1, -1, 0),
+    (1, 0, 0, -1),
+];
+
+struct Shadowcast<'a> {
+    size:    Size,
+    origin:  Point,
+    radius:  isize,
+    opaque:  &'a [bool],
+    visible: &'a mut [bool],
+}
+
+impl Shadowcast<'_> {
+    fn index(
+        &self,
+        dx: isize,
+        dy: isize,
+        (xx, xy, yx, yy): (isize, isize, isize, isize),
+    ) -> Option<usize> {
+        let x = self.origin.x.checked_add_signed(dx * xx + dy * xy)?;
+        let y = self.origin.y.checked_add_signed(dx * yx + dy * yy)?;
+
+        if x < self.size.x && y < self.size.y {
+            Some(y * self.size.x + x)
+        } else {
+            None
+        }
+    }
+
+    fn cast(&mut self, row: isize, mut start: f64, end: f64, octant: (isize, isize, isize, isize)) {
+        if start < end {
+            return;
+        }
+
+        let radius2 = self.radius * self.radius;
+        let mut next_start = start;
+
+        for j in row..=self.radius {
+            let dy = -j;
+            let mut blocked = false;
+
+            for dx in -j..=0 {
+                let left = (dx as f64 - 0.5) / (dy as f64 + 0.5);
+                let right = (dx as f64 + 0.5) / (dy as f64 - 0.5);
+
+                if start < right {
+                    continue;
+                } else if end > left {
+                    break;
+                }
+
+                // Out-of-bounds cells block the view
+                let index = self.index(dx, dy, octant);
+                let opaque = index.is_none_or(|index| self.opaque[index]);
+
+                if let Some(index) = index {
+                    if dx * dx + dy * dy <= radius2 {
+                        self.visible[index] = true;
+                    }
+                }
+
+                if blocked {
+                    if opaque {
+                        next_start = right;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if opaque && j < self.radius {
+                    blocked = true;
+                    self.cast(j + 1, start, left, octant);
+                    next_start = right;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn field_of_view() {
+        let grid = char_grid(
+            (7, 5),
+            "\
+            .......\
+            .......\
+            ..@.#..\
+            .......\
+            .......",
+        );
+        let fov = super::field_of_view(&grid, (2, 2), 10, |c| *c == '#');
+
+        assert_eq!(
+            rows_text(&fov, |v| if *v { 'o' } else { ' ' }).join("|"),
+            "ooooooo|ooooooo|ooooo  |ooooooo|ooooooo"
+        );
+
+        let fov = super::field_of_view(&grid, (2, 2), 1, |c| *c == '#');
+
+        assert_eq!(
+            rows_text(&fov, |v| if *v { 'o' } else { ' ' }).join("|"),
+            "       |  o    | ooo   |  o    |       "
+        );
+
+        let fov = super::field_of_view(&grid, (20, 2), 1, |c| *c == '#');
+
+        assert!(fov.as_ref().iter().all(|v| !v));
+    }
+
+    #[test]
+    fn line_of_sight() {
+        let grid = char_grid(
+            (5, 3),
+            "\
+            .....\
+            ..#..\
+            .....",
+        );
+        let opaque = |c: &char| *c == '#';
+
+        assert!(super::line_of_sight(
+            &grid,
+            (0, 0).into(),
+            (4, 0).into(),
+            opaque
+        ));
+        assert!(super::line_of_sight(
+            &grid,
+            (0, 0).into(),
+            (2, 1).into(),
+            opaque
+        ));
+        assert!(!super::line_of_sight(
+            &grid,
+            (0, 1).into(),
+            (4, 1).into(),
+            opaque
+        ));
+        assert!(!super::line_of_sight(
+            &grid,
+            (0, 1).into(),
+            (9, 1).into(),
+            opaque
+        ));
+    }
+}
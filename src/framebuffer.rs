@@ -0,0 +1,685 @@
+//! Packed pixel framebuffers, e.g. for LED matrices and small displays.
+//!
+//! A [`Framebuffer`](Framebuffer) views bytes (typically a borrowed
+//! `&mut [u8]`) as a grid of pixels packed in a [`Format`](Format):
+//!
+//! | Format                | Pixel     | Bytes                                  |
+//! |-----------------------|-----------|----------------------------------------|
+//! | `Mono<RowMajor, O>`   | `bool`    | rows of `⌈x / 8⌉` bytes                |
+//! | `Mono<ColMajor, O>`   | `bool`    | columns of `⌈y / 8⌉` bytes             |
+//! | `Mono<PageMajor, O>`  | `bool`    | pages of 8 rows, a byte per column     |
+//! | `Rgb565`              | `u16`     | 2 big-endian bytes per pixel           |
+//! | `Rgb888`              | `[u8; 3]` | 3 bytes per pixel                      |
+//!
+//! Monochrome bits are ordered by `O` ([`MsbFirst`](MsbFirst) or
+//! [`LsbFirst`](LsbFirst)). [`PageMajor`](PageMajor) is the SSD1306 layout:
+//! with [`LsbFirst`](LsbFirst), bit `0` of a byte is the topmost pixel.
+//!
+//! Pixels are packed, so `&Framebuffer`s are grids of pixels by value, and
+//! framebuffers are written with [`set`](Framebuffer::set),
+//! [`fill`](Framebuffer::fill), [`copy_from`](Framebuffer::copy_from) and
+//! [`copy_within`](Framebuffer::copy_within).
+//!
+//! Framebuffers are not [`Grid1D`](Grid1D)s: grids of `&Grid1D`s return
+//! `&I`s borrowed from their `AsRef<[I]>` buffer, but packed bits and
+//! big-endian `u16`s do not exist as such in the bytes. `Rgb888` pixels
+//! could be borrowed as `&[u8; 3]`, but display drivers hand out `&mut [u8]`,
+//! so all formats share the same byte-buffered type.
+
+use crate::*;
+use core::{iter::Flatten, marker::PhantomData, ops::Range};
+
+/// A pixel format of a [`Framebuffer`](Framebuffer).
+pub trait Format {
+    /// The type of pixels.
+    type Pixel: Copy;
+
+    /// Returns the number of bytes of a framebuffer of `size`, or
+    /// [`None`](core::option::Option::None) on overflow.
+    fn len(size: Size) -> Option<usize>;
+
+    /// Reads the pixel at `point` of a framebuffer of `size`.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `point` is out of bounds or `bytes` too short.
+    fn read(bytes: &[u8], size: Size, point: Point) -> Self::Pixel;
+
+    /// Writes the pixel at `point` of a framebuffer of `size`.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `point` is out of bounds or `bytes` too short.
+    fn write(bytes: &mut [u8], size: Size, point: Point, pixel: Self::Pixel);
+}
+
+/// Where the bits of a monochrome [`Framebuffer`](Framebuffer) are.
+///
+/// Unlike [`Major`](Major)s, which index items, bit layouts locate bits in
+/// bytes.
+pub trait BitLayout {
+    /// Returns the number of bytes of a framebuffer of `size`, or
+    /// [`None`](core::option::Option::None) on overflow.
+    fn len(size: Size) -> Option<usize>;
+
+    /// Returns the byte and bit (`0..8`, in layout order) of `point` in a
+    /// framebuffer of `size`.
+    fn locate(size: Size, point: Point) -> (usize, u32);
+}
+
+/// The order of bits in a byte of a monochrome
+/// [`Framebuffer`](Framebuffer).
+pub trait BitOrder {
+    /// Returns the mask of the `bit`th bit (`0..8`, in layout order).
+    fn mask(bit: u32) -> u8;
+}
+
+/// Pages of 8 rows, each byte being 8 vertical pixels of a column (SSD1306
+/// layout).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct PageMajor;
+
+/// The first pixel is the most significant bit.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct MsbFirst;
+
+/// The first pixel is the least significant bit.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct LsbFirst;
+
+impl BitLayout for RowMajor {
+    fn len(Size { x, y }: Size) -> Option<usize> {
+        x.div_ceil(8).checked_mul(y)
+    }
+
+    fn locate(Size { x, .. }: Size, point: Point) -> (usize, u32) {
+        (point.y * x.div_ceil(8) + point.x / 8, (point.x % 8) as u32)
+    }
+}
+
+impl BitLayout for ColMajor {
+    fn len(Size { x, y }: Size) -> Option<usize> {
+        y.div_ceil(8).checked_mul(x)
+    }
+
+    fn locate(Size { y, .. }: Size, point: Point) -> (usize, u32) {
+        (point.x * y.div_ceil(8) + point.y / 8, (point.y % 8) as u32)
+    }
+}
+
+impl BitLayout for PageMajor {
+    fn len(Size { x, y }: Size) -> Option<usize> {
+        y.div_ceil(8).checked_mul(x)
+    }
+
+    fn locate(Size { x, .. }: Size, point: Point) -> (usize, u32) {
+        ((point.y / 8) * x + point.x, (point.y % 8) as u32)
+    }
+}
+
+impl BitOrder for MsbFirst {
+    fn mask(bit: u32) -> u8 {
+        0x80 >> bit
+    }
+}
+
+impl BitOrder for LsbFirst {
+    fn mask(bit: u32) -> u8 {
+        1 << bit
+    }
+}
+
+/// 1 bit per pixel monochrome, `true` being on.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Mono<L = RowMajor, O = MsbFirst>(PhantomData<(L, O)>);
+
+/// 16 bits per pixel RGB, big-endian (`rrrrrggg gggbbbbb`).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Rgb565;
+
+/// 24 bits per pixel RGB, as `[r, g, b]` bytes.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Rgb888;
+
+impl<L: BitLayout, O: BitOrder> Format for Mono<L, O> {
+    type Pixel = bool;
+
+    fn len(size: Size) -> Option<usize> {
+        L::len(size)
+    }
+
+    fn read(bytes: &[u8], size: Size, point: Point) -> Self::Pixel {
+        let (byte, bit) = L::locate(size, point);
+
+        bytes[byte] & O::mask(bit) != 0
+    }
+
+    fn write(bytes: &mut [u8], size: Size, point: Point, pixel: Self::Pixel) {
+        let (byte, bit) = L::locate(size, point);
+
+        if pixel {
+            bytes[byte] |= O::mask(bit);
+        } else {
+            bytes[byte] &= !O::mask(bit);
+        }
+    }
+}
+
+impl Rgb565 {
+    /// Packs an RGB888 color, truncating.
+    pub fn pack([r, g, b]: [u8; 3]) -> u16 {
+        (u16::from(r) >> 3) << 11 | (u16::from(g) >> 2) << 5 | u16::from(b) >> 3
+    }
+
+    /// Unpacks to an RGB888 color, replicating high bits in low bits.
+    pub fn unpack(pixel: u16) -> [u8; 3] {
+        let (r, g, b) = (
+            (pixel >> 11) as u8,
+            (pixel >> 5 & 0x3f) as u8,
+            (pixel & 0x1f) as u8,
+        );
+
+        [r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2]
+    }
+}
+
+macro_rules! bytes {
+    ($($Format:ident $Pixel:ty, $n:literal, $from:expr, $to:expr;)*) => { $(
+        impl Format for $Format {
+            type Pixel = $Pixel;
+
+            fn len(Size { x, y }: Size) -> Option<usize> {
+                x.checked_mul(y)?.checked_mul($n)
+            }
+
+            fn read(bytes: &[u8], Size { x, .. }: Size, point: Point) -> Self::Pixel {
+                let start = (point.y * x + point.x) * $n;
+                let mut pixel = [0; $n];
+
+                pixel.copy_from_slice(&bytes[start..start + $n]);
+                $from(pixel)
+            }
+
+            fn write(bytes: &mut [u8], Size { x, .. }: Size, point: Point, pixel: Self::Pixel) {
+                let start = (point.y * x + point.x) * $n;
+
+                bytes[start..start + $n].copy_from_slice(&$to(pixel));
+            }
+        }
+    )* };
+}
+
+bytes!(
+    Rgb565 u16, 2, u16::from_be_bytes, u16::to_be_bytes;
+    Rgb888 [u8; 3], 3, core::convert::identity, core::convert::identity;
+);
+
+/// A monochrome row-major, MSB-first [`Framebuffer`](Framebuffer).
+pub type MonoFramebuffer<B> = Framebuffer<Mono, B>;
+
+/// A monochrome page-major, LSB-first [`Framebuffer`](Framebuffer) (SSD1306
+/// layout).
+pub type PagedFramebuffer<B> = Framebuffer<Mono<PageMajor, LsbFirst>, B>;
+
+/// An [`Rgb565`](Rgb565) [`Framebuffer`](Framebuffer).
+pub type Rgb565Framebuffer<B> = Framebuffer<Rgb565, B>;
+
+/// An [`Rgb888`](Rgb888) [`Framebuffer`](Framebuffer).
+pub type Rgb888Framebuffer<B> = Framebuffer<Rgb888, B>;
+
+/// A grid of pixels packed in bytes, in [`Format`](Format) `F`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Framebuffer<F, B> {
+    size:    Size,
+    bytes:   B,
+    phantom: PhantomData<F>,
+}
+
+/// ### Constructors
+impl<F: Format, B> Framebuffer<F, B> {
+    /// Creates a new [`Framebuffer`](Framebuffer), without checking `bytes`'
+    /// length.
+    pub fn new_unchecked(size: Size, bytes: B) -> Self {
+        Self {
+            size,
+            bytes,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new [`Framebuffer`](Framebuffer), or returns
+    /// [`None`](core::option::Option::None) if `bytes` do not have
+    /// [`F::len(size)`](Format::len) bytes.
+    pub fn new(size: Size, bytes: B) -> Option<Self>
+    where
+        B: AsRef<[u8]>,
+    {
+        if F::len(size)? == bytes.as_ref().len() {
+            Some(Self::new_unchecked(size, bytes))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the underlying bytes.
+    pub fn into_bytes(self) -> B {
+        self.bytes
+    }
+}
+
+/// ### Writing
+impl<F: Format, B: AsMut<[u8]>> Framebuffer<F, B> {
+    /// Sets the pixel at `index`, or returns
+    /// [`None`](core::option::Option::None) if out of bounds.
+    pub fn set(&mut self, index: impl Index0D, pixel: F::Pixel) -> Option<()> {
+        let point = index.checked(self.size)?;
+
+        F::write(self.bytes.as_mut(), self.size, point, pixel);
+        Some(())
+    }
+
+    /// Sets the pixels at `index` to `pixel`, or returns
+    /// [`None`](core::option::Option::None) if out of bounds.
+    pub fn fill(&mut self, index: impl Index2D, pixel: F::Pixel) -> Option<()> {
+        let Rect { x, y } = index.checked(self.size)?;
+        let (size, bytes) = (self.size, self.bytes.as_mut());
+
+        for y in y {
+            for x in x.clone() {
+                F::write(bytes, size, Point { x, y }, pixel);
+            }
+        }
+
+        Some(())
+    }
+
+    /// Copies the pixels of `grid` at `index` (its top-left corner), or
+    /// returns [`None`](core::option::Option::None) if out of bounds.
+    pub fn copy_from<G>(&mut self, index: impl Index0D, grid: G) -> Option<()>
+    where
+        G: GridRows,
+        G::Item: Into<F::Pixel>,
+    {
+        let Point { x, y } = index.unchecked();
+        let Size { x: w, y: h } = grid.size();
+        let end = Point {
+            x: x.checked_add(w)?,
+            y: y.checked_add(h)?,
+        };
+
+        if end.x > self.size.x || end.y > self.size.y {
+            return None;
+        }
+
+        let (size, bytes) = (self.size, self.bytes.as_mut());
+
+        // SAFETY: RangeFull is always in bounds
+        for (dy, row) in unsafe { grid.rows_unchecked(..) }.into_iter().enumerate() {
+            for (dx, pixel) in row.into_iter().enumerate() {
+                F::write(
+                    bytes,
+                    size,
+                    Point {
+                        x: x + dx,
+                        y: y + dy,
+                    },
+                    pixel.into(),
+                );
+            }
+        }
+
+        Some(())
+    }
+
+    /// Copies the pixels at `src` to `dst` (the top-left corner of the
+    /// destination), or returns [`None`](core::option::Option::None) if out
+    /// of bounds.
+    ///
+    /// Source and destination may overlap, e.g. to scroll.
+    pub fn copy_within(&mut self, src: impl Index2D, dst: impl Index0D) -> Option<()> {
+        let Rect { x, y } = src.checked(self.size)?;
+        let Point { x: dx, y: dy } = dst.unchecked();
+        let (w, h) = (x.end - x.start, y.end - y.start);
+
+        if dx.checked_add(w)? > self.size.x || dy.checked_add(h)? > self.size.y {
+            return None;
+        }
+
+        let (size, bytes) = (self.size, self.bytes.as_mut());
+        let copy = |bytes: &mut [u8], i: usize, j: usize| {
+            let pixel = F::read(bytes, size, Point {
+                x: x.start + i,
+                y: y.start + j,
+            });
+            F::write(
+                bytes,
+                size,
+                Point {
+                    x: dx + i,
+                    y: dy + j,
+                },
+                pixel,
+            );
+        };
+
+        // Copy away from the destination, not to overwrite unread pixels
+        for j in 0..h {
+            let j = if dy > y.start { h - 1 - j } else { j };
+
+            for i in 0..w {
+                let i = if dx > x.start { w - 1 - i } else { i };
+
+                copy(bytes, i, j);
+            }
+        }
+
+        Some(())
+    }
+}
+
+impl<F, B: AsRef<[u8]>> AsRef<[u8]> for Framebuffer<F, B> {
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+}
+
+impl<F, B: AsMut<[u8]>> AsMut<[u8]> for Framebuffer<F, B> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.bytes.as_mut()
+    }
+}
+
+impl<F, B> WithSize for Framebuffer<F, B> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<F: Format, B: AsRef<[u8]>> Framebuffer<F, B> {
+    /// Returns `len` pixels from `point`, `step` apart.
+    fn pixels(&self, point: Point, step: Point, len: usize) -> Pixels<'_, F> {
+        Pixels {
+            bytes: self.bytes.as_ref(),
+            size: self.size,
+            point,
+            step,
+            len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<F: Format, B: AsRef<[u8]>> Grid for &Framebuffer<F, B> {
+    type Item = F::Pixel;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        F::read(self.bytes.as_ref(), self.size, index.unchecked())
+    }
+}
+
+impl<'a, F: Format, B: AsRef<[u8]>> GridRow for &'a Framebuffer<F, B> {
+    type Row = Pixels<'a, F>;
+
+    unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+        let (y, Range { start, end }) = index.row_unchecked(self.size);
+
+        self.pixels(Point { x: start, y }, Point { x: 1, y: 0 }, end - start)
+    }
+}
+
+impl<'a, F: Format, B: AsRef<[u8]>> GridCol for &'a Framebuffer<F, B> {
+    type Col = Pixels<'a, F>;
+
+    unsafe fn col_unchecked(self, index: impl Index1D) -> Self::Col {
+        let (x, Range { start, end }) = index.col_unchecked(self.size);
+
+        self.pixels(Point { x, y: start }, Point { x: 0, y: 1 }, end - start)
+    }
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident $lines:ident $range:ident $rows:literal)*) => { $(
+        impl<'a, F: Format, B: AsRef<[u8]>> $Trait for &'a Framebuffer<F, B> {
+            type $Assoc = Lines<'a, F>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let index = index.unchecked(self.size);
+
+                Lines {
+                    bytes:   self.bytes.as_ref(),
+                    size:    self.size,
+                    lines:   index.$lines,
+                    range:   index.$range,
+                    rows:    $rows,
+                    phantom: PhantomData,
+                }
+            }
+        }
+    )* };
+}
+
+grid2d!(
+    GridRows Rows rows_unchecked y x true
+    GridCols Cols cols_unchecked x y false
+);
+
+impl<'a, F: Format, B: AsRef<[u8]>> GridItems for &'a Framebuffer<F, B> {
+    type Items = Flatten<Lines<'a, F>>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+/// Iterator over the pixels of a row or column of a
+/// [`Framebuffer`](Framebuffer).
+#[derive(Debug)]
+pub struct Pixels<'a, F> {
+    bytes:   &'a [u8],
+    size:    Size,
+    point:   Point,
+    step:    Point,
+    len:     usize,
+    phantom: PhantomData<F>,
+}
+
+impl<'a, F: Format> Iterator for Pixels<'a, F> {
+    type Item = F::Pixel;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            let pixel = F::read(self.bytes, self.size, self.point);
+
+            self.len -= 1;
+            if self.len != 0 {
+                self.point.x += self.step.x;
+                self.point.y += self.step.y;
+            }
+
+            Some(pixel)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, F: Format> DoubleEndedIterator for Pixels<'a, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+
+            let point = Point {
+                x: self.point.x + self.len * self.step.x,
+                y: self.point.y + self.len * self.step.y,
+            };
+
+            Some(F::read(self.bytes, self.size, point))
+        }
+    }
+}
+
+impl<'a, F: Format> ExactSizeIterator for Pixels<'a, F> {}
+
+/// Iterator over the rows or columns of a [`Framebuffer`](Framebuffer).
+#[derive(Debug)]
+pub struct Lines<'a, F> {
+    bytes:   &'a [u8],
+    size:    Size,
+    lines:   Range<usize>,
+    range:   Range<usize>,
+    rows:    bool,
+    phantom: PhantomData<F>,
+}
+
+impl<'a, F: Format> Lines<'a, F> {
+    fn line(&self, i: usize) -> Pixels<'a, F> {
+        let (point, step) = if self.rows {
+            (
+                Point {
+                    x: self.range.start,
+                    y: i,
+                },
+                Point { x: 1, y: 0 },
+            )
+        } else {
+            (
+                Point {
+                    x: i,
+                    y: self.range.start,
+                },
+                Point { x: 0, y: 1 },
+            )
+        };
+
+        Pixels {
+            bytes: self.bytes,
+            size: self.size,
+            point,
+            step,
+            len: self.range.end - self.range.start,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, F: Format> Iterator for Lines<'a, F> {
+    type Item = Pixels<'a, F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.lines.next()?;
+
+        Some(self.line(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+impl<'a, F: Format> DoubleEndedIterator for Lines<'a, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let i = self.lines.next_back()?;
+
+        Some(self.line(i))
+    }
+}
+
+impl<'a, F: Format> ExactSizeIterator for Lines<'a, F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn mono() {
+        let mut bytes = [0; 4];
+        let mut fb = MonoFramebuffer::new((10, 2).into(), &mut bytes[..]).unwrap();
+
+        fb.set((9, 1), true).unwrap();
+        fb.fill((1..3, ..1), true).unwrap();
+        assert_eq!(fb.set((10, 0), true), None);
+        assert_eq!(fb.as_ref(), &[0b0110_0000, 0, 0, 0b0100_0000]);
+        assert_eq!((&fb).item((9, 1)), Some(true));
+        assert_eq!((&fb).row((0, ..4)).unwrap().collect::<Vec<_>>(), [
+            false, true, true, false
+        ]);
+
+        let mut bytes = [0; 4];
+        let mut fb =
+            Framebuffer::<Mono<RowMajor, LsbFirst>, _>::new((10, 2).into(), &mut bytes[..])
+                .unwrap();
+        fb.set((9, 1), true).unwrap();
+        assert_eq!(bytes, [0, 0, 0, 0b10]);
+        assert!(MonoFramebuffer::new((10, 2).into(), [0; 3]).is_none());
+    }
+
+    #[test]
+    fn paged() {
+        let mut fb = PagedFramebuffer::new((3, 10).into(), [0; 6]).unwrap();
+
+        fb.set((1, 9), true).unwrap();
+        fb.fill((2..3, ..), true).unwrap();
+        assert_eq!(fb.as_ref(), &[0, 0, 0xff, 0, 0b10, 0b11]);
+        assert_eq!((&fb).col((1, 7..)).unwrap().collect::<Vec<_>>(), [
+            false, false, true
+        ]);
+        assert_eq!((&fb).items(..).unwrap().filter(|&on| on).count(), 11);
+
+        // Double-ended
+        assert_eq!((&fb).col((1, 7..)).unwrap().rev().collect::<Vec<_>>(), [
+            true, false, false
+        ]);
+        let mut col = (&fb).col(1).unwrap();
+        assert_eq!(
+            (col.next_back(), col.next(), col.len()),
+            (Some(true), Some(false), 8)
+        );
+        assert_eq!(
+            (&fb)
+                .rows((.., 8..))
+                .unwrap()
+                .rev()
+                .map(|row| row.collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [[false, true, true], [false, false, true]]
+        );
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(Rgb565::pack([255, 0, 0]), 0xf800);
+        assert_eq!(Rgb565::unpack(0x07e0), [0, 255, 0]);
+
+        let mut fb = Rgb565Framebuffer::new((2, 1).into(), [0; 4]).unwrap();
+        fb.set((1, 0), Rgb565::pack([0, 0, 255])).unwrap();
+        assert_eq!(fb.into_bytes(), [0, 0, 0x00, 0x1f]);
+
+        let mut fb = Rgb888Framebuffer::new((3, 3).into(), vec![0; 27]).unwrap();
+        let red = RowGrid1D::new((2, 1).into(), vec![[255, 0, 0], [254, 0, 0]]).unwrap();
+        fb.copy_from((1, 2), (&red).copied()).unwrap();
+        assert_eq!(fb.copy_from((2, 2), (&red).copied()), None);
+        assert_eq!((&fb).row(2).unwrap().collect::<Vec<_>>(), [
+            [0, 0, 0],
+            [255, 0, 0],
+            [254, 0, 0]
+        ]);
+
+        fb.copy_within((1.., 2..), (0, 2)).unwrap();
+        fb.copy_within((..2, 2..), (1, 2)).unwrap();
+        assert_eq!(fb.copy_within((1.., ..), (2, 0)), None);
+        assert_eq!((&fb).row(2).unwrap().collect::<Vec<_>>(), [
+            [255, 0, 0],
+            [255, 0, 0],
+            [254, 0, 0]
+        ]);
+        assert_eq!((&fb).col(0).unwrap().filter(|&p| p == [0; 3]).count(), 2);
+    }
+}
@@ -16,7 +16,7 @@ pub trait Grid: WithSize + Sized {
     /// behavior*.
     unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item;
 
-    /// Returns the item at `index`, or [`None`](std::option::Option::None) if
+    /// Returns the item at `index`, or [`None`](core::option::Option::None) if
     /// out of bounds.
     fn item(self, index: impl Index0D) -> Option<Self::Item> {
         let index = index.checked(self.size())?;
@@ -25,6 +25,15 @@ pub trait Grid: WithSize + Sized {
         Some(unsafe { self.item_unchecked(index) })
     }
 
+    /// Returns the item at `index`, or a [`GridError`](GridError) if out of
+    /// bounds.
+    fn try_item(self, index: impl Index0D) -> Result<Self::Item, GridError> {
+        let index = index.try_checked(self.size())?;
+
+        // SAFETY: index is checked
+        Ok(unsafe { self.item_unchecked(index) })
+    }
+
     /// Creates a grid which copies all of its elements.
     ///
     /// This is useful when you have an iterator over `&T`, but you need an
@@ -51,15 +60,26 @@ pub trait Grid: WithSize + Sized {
 
     /// Creates a new grid by cropping with `rect`, without bounds
     /// checking.
+    ///
+    /// ### Safety
+    ///
+    /// Calling this method with an out-of-bounds `rect` is *undefined
+    /// behavior*.
     unsafe fn cropped_unchecked(self, rect: impl Index2D) -> Cropped<Self> {
         Cropped::new_unchecked(rect, self)
     }
 
     /// Creates a new grid by cropping with `rect`, or
-    /// [`None`](std::option::Option::None) if out of bounds.
+    /// [`None`](core::option::Option::None) if out of bounds.
     fn cropped(self, rect: impl Index2D) -> Option<Cropped<Self>> {
         Cropped::new(rect, self)
     }
+
+    /// Creates a new grid by cropping with `rect`, or a
+    /// [`GridError`](GridError) if out of bounds.
+    fn try_cropped(self, rect: impl Index2D) -> Result<Cropped<Self>, GridError> {
+        Cropped::try_new(rect, self)
+    }
 }
 
 macro_rules! grid1d {
@@ -70,6 +90,8 @@ macro_rules! grid1d {
         $unchecked:ident
         $(#[$checked_meta:meta])*
         $checked:ident
+        $(#[$try_meta:meta])*
+        $try:ident
     )*) => { $(
         $(#[$meta])*
         pub trait $Trait: Grid {
@@ -85,6 +107,14 @@ macro_rules! grid1d {
                 // SAFETY: index is checked
                 Some(unsafe { self.$unchecked(index) })
             }
+
+            $(#[$try_meta])*
+            fn $try(self, index: impl Index1D) -> Result<Self::$Assoc, GridError> {
+                let index = index.$try(self.size())?;
+
+                // SAFETY: index is checked
+                Ok(unsafe { self.$unchecked(index) })
+            }
         }
     )* };
 }
@@ -97,6 +127,8 @@ macro_rules! grid2d {
         $unchecked:ident
         $(#[$checked_meta:meta])*
         $checked:ident
+        $(#[$try_meta:meta])*
+        $try:ident
     )*) => { $(
         $(#[$meta])*
         pub trait $Trait: $Parent {
@@ -112,12 +144,20 @@ macro_rules! grid2d {
                 // SAFETY: index is checked
                 Some(unsafe { self.$unchecked(index) })
             }
+
+            $(#[$try_meta])*
+            fn $try(self, index: impl Index2D) -> Result<Self::$Assoc, GridError> {
+                let index = index.try_checked(self.size())?;
+
+                // SAFETY: index is checked
+                Ok(unsafe { self.$unchecked(index) })
+            }
         }
     )* };
 }
 
 grid1d!(
-    /// Provides a [`Col`](GridCol::Col) 1D [`IntoIterator`](std::iter::IntoIterator).
+    /// Provides a [`Col`](GridCol::Col) 1D [`IntoIterator`](core::iter::IntoIterator).
     GridCol Col
         /// Returns the column at `index`, without bounds checking.
         ///
@@ -126,10 +166,13 @@ grid1d!(
         /// Calling this method with an out-of-bounds `index` is *undefined
         /// behavior*.
         col_unchecked
-        /// Returns the column at `index`, or [`None`](std::option::Option::None) if
+        /// Returns the column at `index`, or [`None`](core::option::Option::None) if
         /// out of bounds.
         col
-    /// Provides a [`Row`](GridRow::Row) 1D [`IntoIterator`](std::iter::IntoIterator).
+        /// Returns the column at `index`, or a [`GridError`](GridError) if out of
+        /// bounds.
+        try_col
+    /// Provides a [`Row`](GridRow::Row) 1D [`IntoIterator`](core::iter::IntoIterator).
     GridRow Row
         /// Returns the row at `index`, without bounds checking.
         ///
@@ -138,13 +181,16 @@ grid1d!(
         /// Calling this method with an out-of-bounds `index` is *undefined
         /// behavior*.
         row_unchecked
-        /// Returns the row at `index`, or [`None`](std::option::Option::None) if
+        /// Returns the row at `index`, or [`None`](core::option::Option::None) if
         /// out of bounds.
         row
+        /// Returns the row at `index`, or a [`GridError`](GridError) if out of
+        /// bounds.
+        try_row
 );
 
 grid2d!(
-    /// Provides a [`Cols`](GridCols::Cols) 2D [`IntoIterator`](std::iter::IntoIterator).
+    /// Provides a [`Cols`](GridCols::Cols) 2D [`IntoIterator`](core::iter::IntoIterator).
     GridCols Cols (GridCol Col)
         /// Returns the columns at `index`, without bounds checking.
         ///
@@ -153,10 +199,13 @@ grid2d!(
         /// Calling this method with an out-of-bounds `index` is *undefined
         /// behavior*.
         cols_unchecked
-        /// Returns the columns at `index`, or [`None`](std::option::Option::None) if
+        /// Returns the columns at `index`, or [`None`](core::option::Option::None) if
         /// out of bounds.
         cols
-    /// Provides a [`Rows`](GridRows::Rows) 2D [`IntoIterator`](std::iter::IntoIterator).
+        /// Returns the columns at `index`, or a [`GridError`](GridError) if out of
+        /// bounds.
+        try_cols
+    /// Provides a [`Rows`](GridRows::Rows) 2D [`IntoIterator`](core::iter::IntoIterator).
     GridRows Rows (GridRow Row)
         /// Returns the rows at `index`, without bounds checking.
         ///
@@ -165,10 +214,13 @@ grid2d!(
         /// Calling this method with an out-of-bounds `index` is *undefined
         /// behavior*.
         rows_unchecked
-        /// Returns the rows at `index`, or [`None`](std::option::Option::None) if
+        /// Returns the rows at `index`, or [`None`](core::option::Option::None) if
         /// out of bounds.
         rows
-    /// Provides an [`Items`](GridItems::Items) 2D [`IntoIterator`](std::iter::IntoIterator).
+        /// Returns the rows at `index`, or a [`GridError`](GridError) if out of
+        /// bounds.
+        try_rows
+    /// Provides an [`Items`](GridItems::Items) 2D [`IntoIterator`](core::iter::IntoIterator).
     GridItems Items (Grid Item)
         /// Returns the items at `index`, without bounds checking.
         ///
@@ -177,7 +229,10 @@ grid2d!(
         /// Calling this method with an out-of-bounds `index` is *undefined
         /// behavior*.
         items_unchecked
-        /// Returns the items at `index`, or [`None`](std::option::Option::None) if
+        /// Returns the items at `index`, or [`None`](core::option::Option::None) if
         /// out of bounds.
         items
+        /// Returns the items at `index`, or a [`GridError`](GridError) if out of
+        /// bounds.
+        try_items
 );
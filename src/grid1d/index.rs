@@ -1,19 +1,14 @@
 use crate::*;
-use std::ops::Range;
-
-pub fn index0d<M: Major>(point: Point, size: M) -> usize {
-    let point = M::from(point);
-
-    point.minor() * size.major() + point.major()
-}
+use core::ops::Range;
 
 pub fn index1d<M: Major>(
     (i, Range { start, end }): (usize, Range<usize>),
     size: M,
 ) -> Range<usize> {
-    let start = index0d(M::new(start, i).into(), size);
+    let len = end - start;
+    let start = size.index(M::new(start, i).into());
 
-    start..start + end
+    start..start + len
 }
 
 pub fn major_index2d<M: Major>(index: Rect) -> (Range<usize>, Range<usize>) {
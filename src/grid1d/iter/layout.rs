@@ -0,0 +1,219 @@
+use super::*;
+use core::ops::Range;
+
+macro_rules! iter {
+    ($(
+        $(#[$meta:meta])*
+        $Line:ident [$Ptr:ident $($mut:ident)?];
+        $(#[$lines_meta:meta])*
+        $Lines:ident
+    )*) => { $(
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $Line<'a, M, I> {
+            ptr:      *$Ptr I,
+            layout:   M,
+            start:    Point,
+            row:      bool,
+            len:      usize,
+            _phantom: PhantomData<&'a $($mut)? I>,
+        }
+
+        // SAFETY: iterators behave as their references
+        unsafe impl<'a, M: Sync, I: Sync> Sync for $Line<'a, M, I> {}
+
+        impl<'a, M: Layout, I> $Line<'a, M, I> {
+            /// # Safety
+            ///
+            /// The `len` points from `start` (along `x` if `row`, `y`
+            /// otherwise) must be in bounds of `layout`, whose items from
+            /// `ptr` must be valid (for `'a`).
+            pub(crate) unsafe fn new(
+                ptr: *$Ptr I,
+                layout: M,
+                start: Point,
+                row: bool,
+                len: usize,
+            ) -> Self {
+                Self {
+                    ptr,
+                    layout,
+                    start,
+                    row,
+                    len,
+                    _phantom: PhantomData,
+                }
+            }
+
+            /// Returns the `n`th remaining item.
+            ///
+            /// # Safety
+            ///
+            /// `n < self.len`, and items are only returned once.
+            unsafe fn get(&self, n: usize) -> &'a $($mut)? I {
+                let Point { x, y } = self.start;
+                let point = if self.row {
+                    Point { x: x + n, y }
+                } else {
+                    Point { x, y: y + n }
+                };
+
+                // Layouts are injective: distinct points give distinct items
+                &$($mut)? *self.ptr.add(self.layout.index(point))
+            }
+        }
+
+        impl<'a, M: Layout, I> Iterator for $Line<'a, M, I> {
+            type Item = &'a $($mut)? I;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.len == 0 {
+                    None
+                } else {
+                    // SAFETY: users guaranty index is in bounds at construction
+                    let item = unsafe { self.get(0) };
+
+                    self.len -= 1;
+                    if self.row {
+                        self.start.x += 1;
+                    } else {
+                        self.start.y += 1;
+                    }
+
+                    Some(item)
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<'a, M: Layout, I> DoubleEndedIterator for $Line<'a, M, I> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.len == 0 {
+                    None
+                } else {
+                    self.len -= 1;
+
+                    // SAFETY: users guaranty index is in bounds at construction
+                    Some(unsafe { self.get(self.len) })
+                }
+            }
+        }
+
+        impl<'a, M: Layout, I> ExactSizeIterator for $Line<'a, M, I> {}
+
+        $(#[$lines_meta])*
+        #[derive(Debug)]
+        pub struct $Lines<'a, M, I> {
+            ptr:      *$Ptr I,
+            layout:   M,
+            index:    Rect,
+            rows:     bool,
+            _phantom: PhantomData<&'a $($mut)? I>,
+        }
+
+        // SAFETY: iterators behave as their references
+        unsafe impl<'a, M: Sync, I: Sync> Sync for $Lines<'a, M, I> {}
+
+        impl<'a, M: Layout, I> $Lines<'a, M, I> {
+            /// # Safety
+            ///
+            /// `index` must be in bounds of `layout`, whose items from `ptr`
+            /// must be valid (for `'a`).
+            pub(crate) unsafe fn new(ptr: *$Ptr I, layout: M, index: Rect, rows: bool) -> Self {
+                Self {
+                    ptr,
+                    layout,
+                    index,
+                    rows,
+                    _phantom: PhantomData,
+                }
+            }
+
+            fn line(&self, i: usize) -> $Line<'a, M, I> {
+                let Rect { x, y } = &self.index;
+                let (start, len) = if self.rows {
+                    (Point { x: x.start, y: i }, x.len())
+                } else {
+                    (Point { x: i, y: y.start }, y.len())
+                };
+
+                // SAFETY: users guaranty index is in bounds at construction
+                unsafe { $Line::new(self.ptr, self.layout, start, self.rows, len) }
+            }
+
+            fn lines(&mut self) -> &mut Range<usize> {
+                if self.rows {
+                    &mut self.index.y
+                } else {
+                    &mut self.index.x
+                }
+            }
+        }
+
+        impl<'a, M: Layout, I> Iterator for $Lines<'a, M, I> {
+            type Item = $Line<'a, M, I>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let i = self.lines().next()?;
+
+                Some(self.line(i))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = if self.rows { self.index.y.len() } else { self.index.x.len() };
+
+                (len, Some(len))
+            }
+        }
+
+        impl<'a, M: Layout, I> DoubleEndedIterator for $Lines<'a, M, I> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let i = self.lines().next_back()?;
+
+                Some(self.line(i))
+            }
+        }
+
+        impl<'a, M: Layout, I> ExactSizeIterator for $Lines<'a, M, I> {}
+    )* };
+}
+
+iter!(
+    /// Iterator over a row or column of a non-[`Major`](crate::Major)
+    /// [`Grid1D`](crate::Grid1D), indexing each item through its
+    /// [`Layout`](crate::Layout).
+    LayoutLine [const];
+    /// Iterator over the rows or columns of a non-[`Major`](crate::Major)
+    /// [`Grid1D`](crate::Grid1D).
+    LayoutLines
+    /// Mutable iterator over a row or column of a non-[`Major`](crate::Major)
+    /// [`Grid1D`](crate::Grid1D).
+    LayoutLineMut [mut mut];
+    /// Mutable iterator over the rows or columns of a
+    /// non-[`Major`](crate::Major) [`Grid1D`](crate::Grid1D).
+    LayoutLinesMut
+);
+
+// SAFETY: iterators behave as their references
+unsafe impl<'a, M: Send, I: Sync> Send for LayoutLine<'a, M, I> {}
+unsafe impl<'a, M: Send, I: Sync> Send for LayoutLines<'a, M, I> {}
+unsafe impl<'a, M: Send, I: Send> Send for LayoutLineMut<'a, M, I> {}
+unsafe impl<'a, M: Send, I: Send> Send for LayoutLinesMut<'a, M, I> {}
+
+impl<'a, M: Copy, I> Clone for LayoutLine<'a, M, I> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, M: Copy, I> Clone for LayoutLines<'a, M, I> {
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index.clone(),
+            ..*self
+        }
+    }
+}
@@ -11,6 +11,31 @@ impl<'a, M: Major, I, T> Majors<'a, M, I, T> {
 
         Self { grid, index }
     }
+
+    /// Splits at the `index`th major, in O(1).
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let (mut left, mut right) = (self.index.clone(), self.index);
+        let (left_major, right_major) = if is_row_major::<M>() {
+            (&mut left.y, &mut right.y)
+        } else {
+            (&mut left.x, &mut right.x)
+        };
+
+        left_major.end = left_major.start + index;
+        right_major.start = left_major.end;
+
+        (
+            Self {
+                grid:  self.grid,
+                index: left,
+            },
+            Self {
+                grid:  self.grid,
+                index: right,
+            },
+        )
+    }
 }
 
 impl<'a, I, T: AsRef<[I]>> Iterator for Majors<'a, RowMajor, I, T> {
@@ -22,8 +47,23 @@ impl<'a, I, T: AsRef<[I]>> Iterator for Majors<'a, RowMajor, I, T> {
         // SAFETY: users guaranty index is in bounds at construction
         Some(unsafe { self.grid.row_unchecked(index) })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.y.size_hint()
+    }
+}
+
+impl<'a, I, T: AsRef<[I]>> DoubleEndedIterator for Majors<'a, RowMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = (self.index.y.next_back()?, self.index.x.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.row_unchecked(index) })
+    }
 }
 
+impl<'a, I, T: AsRef<[I]>> ExactSizeIterator for Majors<'a, RowMajor, I, T> {}
+
 impl<'a, I, T: AsRef<[I]>> Iterator for Majors<'a, ColMajor, I, T> {
     type Item = &'a [I];
 
@@ -33,4 +73,19 @@ impl<'a, I, T: AsRef<[I]>> Iterator for Majors<'a, ColMajor, I, T> {
         // SAFETY: users guaranty index is in bounds at construction
         Some(unsafe { self.grid.col_unchecked(index) })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.x.size_hint()
+    }
 }
+
+impl<'a, I, T: AsRef<[I]>> DoubleEndedIterator for Majors<'a, ColMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = (self.index.x.next_back()?, self.index.y.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.col_unchecked(index) })
+    }
+}
+
+impl<'a, I, T: AsRef<[I]>> ExactSizeIterator for Majors<'a, ColMajor, I, T> {}
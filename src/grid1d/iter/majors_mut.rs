@@ -1,5 +1,5 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
+use core::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
 
 pub struct MajorsMut<'a, M, I, T> {
     items:    &'a mut [I],
@@ -31,6 +31,32 @@ impl<'a, M: Major, I, T: AsMut<[I]>> MajorsMut<'a, M, I, T> {
     }
 }
 
+impl<'a, M, I, T> MajorsMut<'a, M, I, T> {
+    /// Splits at the `index`th major, in O(1).
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        debug_assert!(index * self.major <= self.items.len());
+        let (left, right) = self.items.split_at_mut(index * self.major);
+
+        (
+            Self {
+                items:    left,
+                range:    self.range.clone(),
+                major:    self.major,
+                count:    index,
+                _phantom: PhantomData,
+            },
+            Self {
+                items:    right,
+                range:    self.range,
+                major:    self.major,
+                count:    self.count - index,
+                _phantom: PhantomData,
+            },
+        )
+    }
+}
+
 impl<'a, M, I, T> Iterator for MajorsMut<'a, M, I, T> {
     type Item = &'a mut [I];
 
@@ -59,4 +85,29 @@ impl<'a, M, I, T> Iterator for MajorsMut<'a, M, I, T> {
             Some(slice)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
 }
+
+impl<'a, M, I, T> DoubleEndedIterator for MajorsMut<'a, M, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            None
+        } else {
+            self.count -= 1;
+
+            let items = core::mem::take(&mut self.items);
+            debug_assert!(self.count * self.major <= items.len());
+            let (items, slice) = items.split_at_mut(self.count * self.major);
+            self.items = items;
+
+            // SAFETY: users guaranty index is in bounds at construction
+            debug_assert!(self.range.end <= slice.len());
+            Some(unsafe { slice.get_unchecked_mut(self.range.clone()) })
+        }
+    }
+}
+
+impl<'a, M, I, T> ExactSizeIterator for MajorsMut<'a, M, I, T> {}
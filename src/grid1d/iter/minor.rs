@@ -1,5 +1,5 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range};
+use core::{marker::PhantomData, ops::Range};
 
 #[derive(Debug)]
 pub struct Minor<'a, M, I, T> {
@@ -17,7 +17,7 @@ impl<'a, M: Major, I, T: AsRef<[I]>> Minor<'a, M, I, T> {
 
         Self {
             items:    grid.as_ref(),
-            current:  index0d(M::new(i, start).into(), msize),
+            current:  msize.index(M::new(i, start).into()),
             count:    end - start,
             by:       msize.major(),
             _phantom: PhantomData,
@@ -1,5 +1,5 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
+use core::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
 
 pub struct MinorMut<'a, M, I, T> {
     items:    &'a mut [I],
@@ -11,6 +11,31 @@ impl<'a, M: Major, I, T> Minors<'a, M, I, T> {
 
         Self { grid, index }
     }
+
+    /// Splits at the `index`th minor, in O(1).
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let (mut left, mut right) = (self.index.clone(), self.index);
+        let (left_minor, right_minor) = if is_row_major::<M>() {
+            (&mut left.x, &mut right.x)
+        } else {
+            (&mut left.y, &mut right.y)
+        };
+
+        left_minor.end = left_minor.start + index;
+        right_minor.start = left_minor.end;
+
+        (
+            Self {
+                grid:  self.grid,
+                index: left,
+            },
+            Self {
+                grid:  self.grid,
+                index: right,
+            },
+        )
+    }
 }
 
 impl<'a, I, T: AsRef<[I]>> Iterator for Minors<'a, RowMajor, I, T> {
@@ -22,8 +47,23 @@ impl<'a, I, T: AsRef<[I]>> Iterator for Minors<'a, RowMajor, I, T> {
         // SAFETY: users guaranty index is in bounds at construction
         Some(unsafe { self.grid.col_unchecked(index) })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.x.size_hint()
+    }
+}
+
+impl<'a, I, T: AsRef<[I]>> DoubleEndedIterator for Minors<'a, RowMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = (self.index.x.next_back()?, self.index.y.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.col_unchecked(index) })
+    }
 }
 
+impl<'a, I, T: AsRef<[I]>> ExactSizeIterator for Minors<'a, RowMajor, I, T> {}
+
 impl<'a, I, T: AsRef<[I]>> Iterator for Minors<'a, ColMajor, I, T> {
     type Item = Minor<'a, ColMajor, I, T>;
 
@@ -33,4 +73,19 @@ impl<'a, I, T: AsRef<[I]>> Iterator for Minors<'a, ColMajor, I, T> {
         // SAFETY: users guaranty index is in bounds at construction
         Some(unsafe { self.grid.row_unchecked(index) })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.y.size_hint()
+    }
 }
+
+impl<'a, I, T: AsRef<[I]>> DoubleEndedIterator for Minors<'a, ColMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = (self.index.y.next_back()?, self.index.x.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.row_unchecked(index) })
+    }
+}
+
+impl<'a, I, T: AsRef<[I]>> ExactSizeIterator for Minors<'a, ColMajor, I, T> {}
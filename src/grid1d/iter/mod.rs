@@ -1,3 +1,4 @@
+mod layout;
 mod majors;
 mod majors_mut;
 mod minor;
@@ -5,6 +6,7 @@ mod minor_mut;
 mod minors;
 
 use super::*;
+pub use layout::*;
 pub use majors::*;
 pub use majors_mut::*;
 pub use minor::*;
@@ -1,14 +1,25 @@
 mod index;
 pub mod iter;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 use crate::*;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
 use index::*;
-use std::marker::PhantomData;
 
 pub type ColGrid1D<I, T> = Grid1D<ColMajor, I, T>;
+pub type MortonGrid1D<I, T> = Grid1D<MortonOrder, I, T>;
 pub type RowGrid1D<I, T> = Grid1D<RowMajor, I, T>;
+pub type TiledGrid1D<I, T, const W: usize, const H: usize> = Grid1D<Tiled<W, H>, I, T>;
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq)]
 pub struct Grid1D<M, I, T> {
     size:    M,
     items:   T,
@@ -16,7 +27,7 @@ pub struct Grid1D<M, I, T> {
 }
 
 /// ### Constructors
-impl<M: Major, I, T> Grid1D<M, I, T> {
+impl<M: Layout, I, T> Grid1D<M, I, T> {
     /// Creates a new [`Grid1D`](crate::Grid1D), without checking size.
     pub fn new_unchecked(size: Size, items: T) -> Self {
         Self {
@@ -26,17 +37,54 @@ impl<M: Major, I, T> Grid1D<M, I, T> {
         }
     }
 
-    /// Creates a new [`Grid1D`](crate::Grid1D) if `len != x * y`, `None`
-    /// otherwise.
+    /// Creates a new [`Grid1D`](crate::Grid1D) if `len` is the
+    /// [`Layout`](crate::Layout)'s [`capacity`](crate::Layout::capacity)
+    /// (`x * y` for [`Major`](crate::Major)s), `None` otherwise.
     pub fn new(size: Size, items: T) -> Option<Self>
     where
         T: AsRef<[I]>,
     {
-        if items.as_ref().len() == size.x * size.y {
-            Some(Self::new_unchecked(size, items))
+        Self::try_new(size, items).ok()
+    }
+
+    /// Creates a new [`Grid1D`](crate::Grid1D) if `len` is the
+    /// [`Layout`](crate::Layout)'s [`capacity`](crate::Layout::capacity), or
+    /// returns a [`GridError`](crate::GridError)
+    /// ([`Overflow`](crate::GridError::Overflow) /
+    /// [`LengthMismatch`](crate::GridError::LengthMismatch)).
+    pub fn try_new(size: Size, items: T) -> Result<Self, GridError>
+    where
+        T: AsRef<[I]>,
+    {
+        let expected = M::from(size).capacity().ok_or(GridError::Overflow)?;
+        let actual = items.as_ref().len();
+
+        if actual == expected {
+            Ok(Self::new_unchecked(size, items))
         } else {
-            None
+            Err(GridError::LengthMismatch { expected, actual })
+        }
+    }
+
+    /// Returns the [`Size`](crate::Size) as [`Layout`](crate::Layout).
+    pub fn layout(&self) -> M {
+        self.size
+    }
+}
+
+/// Formats as a `DebugGrid` (with the `alloc` feature), or as the raw layout
+/// with `{:#?}`.
+impl<M: Layout + Debug, I: Debug, T: AsRef<[I]> + Debug> Debug for Grid1D<M, I, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        if !f.alternate() {
+            return self.debug_grid().fmt(f);
         }
+
+        f.debug_struct("Grid1D")
+            .field("size", &self.size)
+            .field("items", &self.items)
+            .finish()
     }
 }
 
@@ -52,7 +100,7 @@ impl<M, I, T: AsMut<[I]>> AsMut<[I]> for Grid1D<M, I, T> {
     }
 }
 
-impl<M: Major, I, T> WithSize for Grid1D<M, I, T> {
+impl<M: Layout, I, T> WithSize for Grid1D<M, I, T> {
     fn size(&self) -> Size {
         self.size.into()
     }
@@ -97,12 +145,11 @@ macro_rules! grid {
         )*
     };
     (impl [ITEM] $As:ident $as:ident $get:ident $(($mut:ident))?) => {
-        impl<'a, M: Major, I, T: $As<[I]>> Grid for &'a $($mut)? Grid1D<M, I, T> {
+        impl<'a, M: Layout, I, T: $As<[I]>> Grid for &'a $($mut)? Grid1D<M, I, T> {
             type Item = &'a $($mut)? I;
 
             unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
-                let msize = self.msize();
-                let index = index0d(index.unchecked(), msize);
+                let index = self.size.index(index.unchecked());
 
                 self.items.$as().$get(index)
             }
@@ -141,7 +188,7 @@ macro_rules! grid {
         $As:ident $(($mut:ident))?
     ) => {
         impl<'a, I, T: $As<[I]>> GridItems for &'a $($mut)? $Type<I, T> {
-            type Items = std::iter::Flatten<<Self as $GridMajors>::$Majors>;
+            type Items = core::iter::Flatten<<Self as $GridMajors>::$Majors>;
 
             unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
                 self.$majors(index).flatten()
@@ -162,3 +209,70 @@ grid!(
         GridCols<Cols> (cols_unchecked)
         GridRows<Rows> (rows_unchecked)
 );
+
+macro_rules! layout {
+    ($([$($generics:tt)*] $M:ty)*) => { $(
+        layout!(impl [$($generics)*] ($M) AsRef as_ref as_ptr LayoutLine LayoutLines);
+        layout!(impl [$($generics)*] ($M) AsMut as_mut as_mut_ptr LayoutLineMut LayoutLinesMut (mut));
+    )* };
+    (impl [$($generics:tt)*] ($M:ty)
+        $As:ident $as:ident $ptr:ident
+        $Line:ident $Lines:ident $(($mut:ident))?
+    ) => {
+        impl<'a, $($generics)* I, T: $As<[I]>> GridRow for &'a $($mut)? Grid1D<$M, I, T> {
+            type Row = iter::$Line<'a, $M, I>;
+
+            unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+                let (y, x) = index.row_unchecked(self.size());
+                let start = Point { x: x.start, y };
+
+                iter::$Line::new(self.items.$as().$ptr(), self.size, start, true, x.len())
+            }
+        }
+
+        impl<'a, $($generics)* I, T: $As<[I]>> GridCol for &'a $($mut)? Grid1D<$M, I, T> {
+            type Col = iter::$Line<'a, $M, I>;
+
+            unsafe fn col_unchecked(self, index: impl Index1D) -> Self::Col {
+                let (x, y) = index.col_unchecked(self.size());
+                let start = Point { x, y: y.start };
+
+                iter::$Line::new(self.items.$as().$ptr(), self.size, start, false, y.len())
+            }
+        }
+
+        impl<'a, $($generics)* I, T: $As<[I]>> GridRows for &'a $($mut)? Grid1D<$M, I, T> {
+            type Rows = iter::$Lines<'a, $M, I>;
+
+            unsafe fn rows_unchecked(self, index: impl Index2D) -> Self::Rows {
+                let index = index.unchecked(self.size());
+
+                iter::$Lines::new(self.items.$as().$ptr(), self.size, index, true)
+            }
+        }
+
+        impl<'a, $($generics)* I, T: $As<[I]>> GridCols for &'a $($mut)? Grid1D<$M, I, T> {
+            type Cols = iter::$Lines<'a, $M, I>;
+
+            unsafe fn cols_unchecked(self, index: impl Index2D) -> Self::Cols {
+                let index = index.unchecked(self.size());
+
+                iter::$Lines::new(self.items.$as().$ptr(), self.size, index, false)
+            }
+        }
+
+        impl<'a, $($generics)* I, T: $As<[I]>> GridItems for &'a $($mut)? Grid1D<$M, I, T> {
+            type Items = core::iter::Flatten<<Self as GridRows>::Rows>;
+
+            unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+                self.rows_unchecked(index).flatten()
+            }
+        }
+    };
+}
+
+// Non-contiguous layouts: lines index each item through the layout
+layout!(
+    [] MortonOrder
+    [const W: usize, const H: usize,] Tiled<W, H>
+);
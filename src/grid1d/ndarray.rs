@@ -0,0 +1,296 @@
+//! [`ndarray`](::ndarray) interoperability.
+//!
+//! [`RowMajor`](crate::RowMajor) grids map to standard (C) layout arrays, and
+//! [`ColMajor`](crate::ColMajor) grids to Fortran layout arrays, axis `0`
+//! being `y` and axis `1` being `x`:
+//!
+//! - `&Grid1D` / `&mut Grid1D` convert into
+//!   [`ArrayView2`](::ndarray::ArrayView2) /
+//!   [`ArrayViewMut2`](::ndarray::ArrayViewMut2) without copying,
+//! - [`ArrayView2`](::ndarray::ArrayView2) /
+//!   [`ArrayViewMut2`](::ndarray::ArrayViewMut2) try to convert into `Grid1D<_,
+//!   I, &[I]>` / `Grid1D<_, I, &mut [I]>` without copying, when contiguous in
+//!   the grid's layout (otherwise giving back the view),
+//! - `Grid1D<_, I, Vec<I>>` and [`Array2`](::ndarray::Array2) convert into each
+//!   other, moving items.
+//!
+//! Arbitrary (e.g. strided) views are grids when wrapped in an
+//! [`NdView`](NdView).
+
+use crate::*;
+use ::ndarray::{s, Array2, ArrayView1, ArrayView2, ArrayViewMut2, Axis, ShapeBuilder};
+use std::{convert::TryFrom, ops::Range, vec::Vec};
+
+macro_rules! convert {
+    ($($Type:ident ($($f:ident)?) [$($reversed:ident)?])*) => { $(
+        impl<'a, I, T: AsRef<[I]>> From<&'a $Type<I, T>> for ArrayView2<'a, I> {
+            fn from(grid: &'a $Type<I, T>) -> Self {
+                let Size { x, y } = grid.size();
+
+                Self::from_shape((y, x)$(.$f())?, grid.as_ref()).expect("Grid1D has x * y items")
+            }
+        }
+
+        impl<'a, I, T: AsMut<[I]>> From<&'a mut $Type<I, T>> for ArrayViewMut2<'a, I> {
+            fn from(grid: &'a mut $Type<I, T>) -> Self {
+                let Size { x, y } = grid.size();
+
+                Self::from_shape((y, x)$(.$f())?, grid.as_mut()).expect("Grid1D has x * y items")
+            }
+        }
+
+        impl<I> From<$Type<I, Vec<I>>> for Array2<I> {
+            fn from(grid: $Type<I, Vec<I>>) -> Self {
+                let Size { x, y } = grid.size();
+
+                Self::from_shape_vec((y, x)$(.$f())?, grid.items).expect("Grid1D has x * y items")
+            }
+        }
+
+        impl<'a, I> TryFrom<ArrayView2<'a, I>> for $Type<I, &'a [I]> {
+            type Error = ArrayView2<'a, I>;
+
+            /// Fails if `view` is not contiguous in this layout.
+            fn try_from(view: ArrayView2<'a, I>) -> Result<Self, Self::Error> {
+                let (y, x) = view.dim();
+
+                match view$(.$reversed())?.to_slice() {
+                    Some(items) => Ok(Self::new_unchecked(Size { x, y }, items)),
+                    None => Err(view),
+                }
+            }
+        }
+
+        impl<'a, I> TryFrom<ArrayViewMut2<'a, I>> for $Type<I, &'a mut [I]> {
+            type Error = ArrayViewMut2<'a, I>;
+
+            /// Fails if `view` is not contiguous in this layout.
+            fn try_from(view: ArrayViewMut2<'a, I>) -> Result<Self, Self::Error> {
+                let (y, x) = view.dim();
+
+                if !view.view()$(.$reversed())?.is_standard_layout() {
+                    return Err(view);
+                }
+
+                let items = view$(.$reversed())?.into_slice().expect("contiguous view");
+                Ok(Self::new_unchecked(Size { x, y }, items))
+            }
+        }
+
+        impl<I> From<Array2<I>> for $Type<I, Vec<I>> {
+            fn from(array: Array2<I>) -> Self {
+                let (y, x) = array.dim();
+
+                Self::new_unchecked(Size { x, y }, array$(.$reversed())?.into_iter().collect())
+            }
+        }
+    )* };
+}
+
+convert!(
+    RowGrid1D () []
+    ColGrid1D (f) [reversed_axes]
+);
+
+/// An [`ArrayView2`](::ndarray::ArrayView2) as a grid.
+///
+/// Axis `0` is `y` and axis `1` is `x`, whatever the view's strides.
+#[derive(Copy, Clone, Debug)]
+pub struct NdView<'a, I>(pub ArrayView2<'a, I>);
+
+impl<'a, I> From<ArrayView2<'a, I>> for NdView<'a, I> {
+    fn from(view: ArrayView2<'a, I>) -> Self {
+        Self(view)
+    }
+}
+
+impl<'a, I> WithSize for NdView<'a, I> {
+    fn size(&self) -> Size {
+        let (y, x) = self.0.dim();
+
+        Size { x, y }
+    }
+}
+
+impl<'a, I> Grid for NdView<'a, I> {
+    type Item = &'a I;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Point { x, y } = index.unchecked();
+
+        self.0.slice_move(s![y, x]).into_scalar()
+    }
+}
+
+impl<'a, I> GridRow for NdView<'a, I> {
+    type Row = ArrayView1<'a, I>;
+
+    unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+        let (y, x) = index.row_unchecked(self.size());
+
+        self.0.slice_move(s![y, x])
+    }
+}
+
+impl<'a, I> GridCol for NdView<'a, I> {
+    type Col = ArrayView1<'a, I>;
+
+    unsafe fn col_unchecked(self, index: impl Index1D) -> Self::Col {
+        let (x, y) = index.col_unchecked(self.size());
+
+        self.0.slice_move(s![y, x])
+    }
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident $axis:literal)*) => { $(
+        impl<'a, I> $Trait for NdView<'a, I> {
+            type $Assoc = NdLines<'a, I>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let Point { x, y } = index.unchecked(self.size());
+                let view = self.0.slice_move(s![y, x]);
+
+                NdLines {
+                    range: 0..view.len_of(Axis($axis)),
+                    axis: Axis($axis),
+                    view,
+                }
+            }
+        }
+    )* };
+}
+
+grid2d!(
+    GridRows Rows rows_unchecked 0
+    GridCols Cols cols_unchecked 1
+);
+
+impl<'a, I> GridItems for NdView<'a, I> {
+    type Items = ::ndarray::iter::Iter<'a, I, ::ndarray::Ix2>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        let Point { x, y } = index.unchecked(self.size());
+
+        self.0.slice_move(s![y, x]).into_iter()
+    }
+}
+
+/// Iterator over the rows or columns of an [`NdView`](NdView).
+#[derive(Clone, Debug)]
+pub struct NdLines<'a, I> {
+    view:  ArrayView2<'a, I>,
+    axis:  Axis,
+    range: Range<usize>,
+}
+
+impl<'a, I> Iterator for NdLines<'a, I> {
+    type Item = ArrayView1<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.range.next()?;
+
+        Some(self.view.index_axis_move(self.axis, i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, I> DoubleEndedIterator for NdLines<'a, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let i = self.range.next_back()?;
+
+        Some(self.view.index_axis_move(self.axis, i))
+    }
+}
+
+impl<'a, I> ExactSizeIterator for NdLines<'a, I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::ndarray::{arr2, Array2};
+    use pretty_assertions::assert_eq;
+    use std::vec;
+
+    #[test]
+    fn views() {
+        let mut grid = RowGrid1D::new((3, 2).into(), vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(ArrayView2::from(&grid), arr2(&[[1, 2, 3], [4, 5, 6]]));
+
+        ArrayViewMut2::from(&mut grid)
+            .column_mut(1)
+            .iter_mut()
+            .for_each(|item| *item *= 10);
+        assert_eq!(grid.as_ref(), &[1, 20, 3, 4, 50, 6]);
+
+        let cols = ColGrid1D::new((3, 2).into(), vec![1, 4, 2, 5, 3, 6]).unwrap();
+        let view = ArrayView2::from(&cols);
+        assert_eq!(view, arr2(&[[1, 2, 3], [4, 5, 6]]));
+
+        assert_eq!(ColGrid1D::try_from(view).unwrap().as_ref(), cols.as_ref());
+        assert!(RowGrid1D::try_from(view).is_err());
+        assert!(ColGrid1D::try_from(view.slice_move(s![.., ..;2])).is_err());
+
+        let mut array = arr2(&[[1, 2], [3, 4]]);
+        let mut grid = RowGrid1D::try_from(array.view_mut()).unwrap();
+        *(&mut grid).item((1, 0)).unwrap() = 20;
+        assert!(ColGrid1D::try_from(array.view_mut()).is_err());
+        assert_eq!(array, arr2(&[[1, 20], [3, 4]]));
+    }
+
+    #[test]
+    fn owned() {
+        let array = arr2(&[[1, 2, 3], [4, 5, 6]]);
+
+        let grid = ColGrid1D::from(array.clone());
+        assert_eq!(grid.as_ref(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(Array2::from(grid), array);
+
+        let grid = RowGrid1D::from(array.t().to_owned());
+        assert_eq!(grid.size(), Size { x: 2, y: 3 });
+        assert_eq!(grid.as_ref(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(Array2::from(grid), array.t());
+    }
+
+    #[test]
+    fn nd_view() {
+        let array = Array2::from_shape_fn((4, 5), |(y, x)| 10 * y + x);
+        let view = NdView(array.slice(s![.., ..;2]));
+
+        assert_eq!(view.size(), Size { x: 3, y: 4 });
+        assert_eq!(view.item((2, 1)), Some(&14));
+        assert_eq!(view.item((3, 1)), None);
+        assert_eq!(
+            view.row((1, 1..)).unwrap().into_iter().collect::<Vec<_>>(),
+            [&12, &14]
+        );
+        assert_eq!(
+            view.cols((1.., 2..))
+                .unwrap()
+                .map(|col| col.sum())
+                .collect::<Vec<_>>(),
+            [54, 58]
+        );
+
+        let cropped = view.cropped((1.., 1..3)).unwrap();
+        assert_eq!(cropped.size(), Size { x: 2, y: 2 });
+        assert_eq!(
+            cropped
+                .clone()
+                .items(..)
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>(),
+            [12, 14, 22, 24]
+        );
+        assert_eq!(
+            unsafe { cropped.rows_unchecked(..) }
+                .map(|row| row.to_vec())
+                .collect::<Vec<_>>(),
+            [[12, 14], [22, 24]]
+        );
+    }
+}
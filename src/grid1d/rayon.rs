@@ -0,0 +1,308 @@
+//! [`rayon`](::rayon) parallel iterators for [`Grid1D`](crate::Grid1D).
+//!
+//! [`Majors`](super::iter::Majors), [`MajorsMut`](super::iter::MajorsMut)
+//! and [`Minors`](super::iter::Minors) implement
+//! [`IntoParallelIterator`](::rayon::iter::IntoParallelIterator), splitting
+//! their ranges in O(1) with the grid's strides, so any `rows`/`cols` of a
+//! [`Grid1D`](crate::Grid1D) can be processed in parallel:
+//!
+//! ```
+//! use grid::{GridRows, RowGrid1D};
+//! use rayon::prelude::*;
+//!
+//! let grid = RowGrid1D::new((3, 2).into(), vec![1, 2, 3, 4, 5, 6]).unwrap();
+//! let sums = (&grid)
+//!     .rows(..)
+//!     .unwrap()
+//!     .into_par_iter()
+//!     .map(|row| row.iter().sum())
+//!     .collect::<Vec<i32>>();
+//!
+//! assert_eq!(sums, [6, 15]);
+//! ```
+
+use super::iter::*;
+use crate::*;
+use ::rayon::{
+    iter::{
+        plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer},
+        IndexedParallelIterator,
+        IntoParallelIterator,
+        IntoParallelRefIterator,
+        IntoParallelRefMutIterator,
+        ParallelIterator,
+    },
+    slice::{Iter, IterMut, ParallelSliceMut},
+};
+
+macro_rules! par {
+    ($($(#[$meta:meta])* $Par:ident<$Iter:ident>)*) => { $(
+        $(#[$meta])*
+        pub struct $Par<'a, M, I, T>($Iter<'a, M, I, T>);
+
+        impl<'a, M, I, T> ParallelIterator for $Par<'a, M, I, T>
+        where
+            M: Major,
+            $Iter<'a, M, I, T>: DoubleEndedIterator + ExactSizeIterator + Send,
+            <$Iter<'a, M, I, T> as Iterator>::Item: Send,
+        {
+            type Item = <$Iter<'a, M, I, T> as Iterator>::Item;
+
+            fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+                bridge(self, consumer)
+            }
+
+            fn opt_len(&self) -> Option<usize> {
+                Some(self.0.len())
+            }
+        }
+
+        impl<'a, M, I, T> IndexedParallelIterator for $Par<'a, M, I, T>
+        where
+            M: Major,
+            $Iter<'a, M, I, T>: DoubleEndedIterator + ExactSizeIterator + Send,
+            <$Iter<'a, M, I, T> as Iterator>::Item: Send,
+        {
+            fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+                bridge(self, consumer)
+            }
+
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+                callback.callback(self)
+            }
+        }
+
+        impl<'a, M, I, T> Producer for $Par<'a, M, I, T>
+        where
+            M: Major,
+            $Iter<'a, M, I, T>: DoubleEndedIterator + ExactSizeIterator + Send,
+            <$Iter<'a, M, I, T> as Iterator>::Item: Send,
+        {
+            type IntoIter = $Iter<'a, M, I, T>;
+            type Item = <$Iter<'a, M, I, T> as Iterator>::Item;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0
+            }
+
+            fn split_at(self, index: usize) -> (Self, Self) {
+                let (left, right) = self.0.split_at(index);
+
+                (Self(left), Self(right))
+            }
+        }
+
+        impl<'a, M, I, T> IntoParallelIterator for $Iter<'a, M, I, T>
+        where
+            M: Major,
+            $Iter<'a, M, I, T>: DoubleEndedIterator + ExactSizeIterator + Send,
+            <$Iter<'a, M, I, T> as Iterator>::Item: Send,
+        {
+            type Item = <$Iter<'a, M, I, T> as Iterator>::Item;
+            type Iter = $Par<'a, M, I, T>;
+
+            fn into_par_iter(self) -> Self::Iter {
+                $Par(self)
+            }
+        }
+    )* };
+}
+
+par!(
+    /// Parallel iterator over the majors of a [`Grid1D`](crate::Grid1D).
+    ParMajors<Majors>
+    /// Parallel iterator over the mutable majors of a
+    /// [`Grid1D`](crate::Grid1D).
+    ParMajorsMut<MajorsMut>
+    /// Parallel iterator over the minors of a [`Grid1D`](crate::Grid1D).
+    ParMinors<Minors>
+);
+
+/// ### Parallel iterators
+impl<M: Major, I, T> Grid1D<M, I, T> {
+    /// Returns a parallel iterator over the items, in memory order.
+    pub fn par_items(&self) -> Iter<'_, I>
+    where
+        I: Sync,
+        T: AsRef<[I]>,
+    {
+        self.as_ref().par_iter()
+    }
+
+    /// Returns a parallel iterator over the mutable items, in memory order.
+    pub fn par_items_mut(&mut self) -> IterMut<'_, I>
+    where
+        I: Send,
+        T: AsMut<[I]>,
+    {
+        self.as_mut().par_iter_mut()
+    }
+
+    /// Returns a parallel iterator over sub-grids of `majors` majors (the
+    /// last one may have less), e.g. bands of rows of a
+    /// [`RowGrid1D`](crate::RowGrid1D).
+    ///
+    /// Grids without items yield no sub-grids.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `majors` is `0`.
+    pub fn par_chunks_mut(
+        &mut self,
+        majors: usize,
+    ) -> impl IndexedParallelIterator<Item = Grid1D<M, I, &mut [I]>> + '_
+    where
+        M: Send + Sync,
+        I: Send,
+        T: AsMut<[I]>,
+    {
+        assert!(majors != 0, "majors must be non-zero");
+        let major = self.msize().major();
+
+        self.as_mut()
+            .par_chunks_mut((majors * major).max(1))
+            .map(move |items| {
+                Grid1D::new_unchecked(M::new(major, items.len() / major).into(), items)
+            })
+    }
+}
+
+macro_rules! par_methods {
+    ($(
+        $Type:ident<$M:ident>
+            $majors:ident $majors_mut:ident $Majors:literal
+            $minors:ident $Minors:literal
+            $majors_unchecked:ident $minors_unchecked:ident
+    )*) => { $(
+        impl<I, T> $Type<I, T> {
+            #[doc = concat!("Returns a parallel iterator over the ", $Majors, ".")]
+            pub fn $majors(&self) -> ParMajors<'_, $M, I, T>
+            where
+                I: Sync,
+                T: AsRef<[I]> + Sync,
+            {
+                // SAFETY: RangeFull is always in bounds
+                unsafe { self.$majors_unchecked(..) }.into_par_iter()
+            }
+
+            #[doc = concat!("Returns a parallel iterator over the mutable ", $Majors, ".")]
+            pub fn $majors_mut(&mut self) -> ParMajorsMut<'_, $M, I, T>
+            where
+                I: Send,
+                T: AsMut<[I]> + Send,
+            {
+                // SAFETY: RangeFull is always in bounds
+                unsafe { self.$majors_unchecked(..) }.into_par_iter()
+            }
+
+            #[doc = concat!("Returns a parallel iterator over the ", $Minors, ".")]
+            pub fn $minors(&self) -> ParMinors<'_, $M, I, T>
+            where
+                I: Sync,
+                T: AsRef<[I]> + Send + Sync,
+            {
+                // SAFETY: RangeFull is always in bounds
+                unsafe { self.$minors_unchecked(..) }.into_par_iter()
+            }
+        }
+    )* };
+}
+
+par_methods!(
+    RowGrid1D<RowMajor>
+        par_rows par_rows_mut "rows"
+        par_cols "columns"
+        rows_unchecked cols_unchecked
+    ColGrid1D<ColMajor>
+        par_cols par_cols_mut "columns"
+        par_rows "rows"
+        cols_unchecked rows_unchecked
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn row_major() {
+        let mut grid = RowGrid1D::new((3, 4).into(), (0..12).collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(
+            grid.par_rows()
+                .with_max_len(1)
+                .map(<[_]>::to_vec)
+                .collect::<Vec<_>>(),
+            (&grid)
+                .rows(..)
+                .unwrap()
+                .map(<[_]>::to_vec)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            grid.par_cols()
+                .with_max_len(1)
+                .rev()
+                .map(|col| col.copied().collect())
+                .collect::<Vec<Vec<_>>>(),
+            vec![vec![2, 5, 8, 11], vec![1, 4, 7, 10], vec![0, 3, 6, 9]]
+        );
+
+        grid.par_rows_mut()
+            .with_max_len(1)
+            .enumerate()
+            .for_each(|(y, row)| row.iter_mut().for_each(|item| *item += y * 100));
+        grid.par_items_mut().for_each(|item| *item *= 2);
+        assert_eq!(grid.as_ref(), &[
+            0, 2, 4, 206, 208, 210, 412, 414, 416, 618, 620, 622
+        ]);
+
+        let (rows, cols) = (&mut grid)
+            .rows((1..3, 1..))
+            .unwrap()
+            .into_par_iter()
+            .with_max_len(1)
+            .rev()
+            .map(|row| (row.len(), row[0]))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        assert_eq!(rows, [2, 2, 2]);
+        assert_eq!(cols, [620, 414, 208]);
+    }
+
+    #[test]
+    fn col_major() {
+        let mut grid = ColGrid1D::new((2, 3).into(), vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        assert_eq!(grid.par_cols().map(<[_]>::to_vec).collect::<Vec<_>>(), [
+            [1, 2, 3],
+            [4, 5, 6]
+        ]);
+        assert_eq!(
+            grid.par_rows()
+                .with_max_len(1)
+                .map(|row| row.sum())
+                .collect::<Vec<i32>>(),
+            [5, 7, 9]
+        );
+        assert_eq!(grid.par_items().sum::<i32>(), 21);
+
+        let sizes = grid
+            .par_chunks_mut(1)
+            .map(|mut chunk| {
+                chunk.par_items_mut().for_each(|item| *item = -*item);
+                chunk.size()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [Size { x: 1, y: 3 }; 2]);
+        assert_eq!(grid.as_ref(), &[-1, -2, -3, -4, -5, -6]);
+
+        let empty = ColGrid1D::<i32, Vec<_>>::new((0, 3).into(), vec![]).unwrap();
+        assert_eq!(empty.par_rows().count(), 3);
+        assert_eq!(empty.par_cols().count(), 0);
+    }
+}
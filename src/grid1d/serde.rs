@@ -0,0 +1,182 @@
+//! [`serde`](::serde) support for [`Grid1D`](crate::Grid1D).
+//!
+//! By default, grids are (de)serialized as `{ size, items }`, items being in
+//! memory order. Use [`rows`](rows) to (de)serialize as nested rows instead:
+//!
+//! ```
+//! use grid::RowGrid1D;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Board {
+//!     #[serde(with = "grid::grid1d::serde::rows")]
+//!     cells: RowGrid1D<u8, Vec<u8>>,
+//! }
+//! ```
+//!
+//! Deserialization fails if `items.len() != x * y` (or if rows differ in
+//! length), as [`Grid1D::new`](crate::Grid1D::new).
+
+use crate::*;
+use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use std::{format, vec::Vec};
+
+#[derive(Serialize)]
+#[serde(rename = "Grid1D")]
+struct GridRef<'a, I> {
+    size:  Size,
+    items: &'a [I],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Grid1D")]
+struct GridOwned<I> {
+    size:  Size,
+    items: Vec<I>,
+}
+
+impl<M: Major, I: Serialize, T: AsRef<[I]>> Serialize for Grid1D<M, I, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GridRef {
+            size:  self.size(),
+            items: self.as_ref(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, M: Major, I: Deserialize<'de>> Deserialize<'de> for Grid1D<M, I, Vec<I>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let GridOwned { size, items } = GridOwned::deserialize(deserializer)?;
+        let len = items.len();
+
+        size.x
+            .checked_mul(size.y)
+            .and_then(|_| Self::new(size, items))
+            .ok_or_else(|| {
+                D::Error::invalid_length(len, &format!("{}x{} items", size.x, size.y).as_str())
+            })
+    }
+}
+
+/// (De)serializes [`Grid1D`](crate::Grid1D)s as nested rows, for
+/// `#[serde(with = "grid::grid1d::serde::rows")]`.
+pub mod rows {
+    use super::*;
+
+    /// Serializes `grid` as a sequence of rows.
+    pub fn serialize<M, I, T, S>(grid: &Grid1D<M, I, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        for<'a> &'a Grid1D<M, I, T>: GridRows<Item = &'a I>,
+        I: Serialize,
+        S: Serializer,
+    {
+        // SAFETY: RangeFull is always in bounds
+        unsafe { grid.rows_unchecked(..) }
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect::<Vec<Vec<_>>>()
+            .serialize(serializer)
+    }
+
+    /// Deserializes a sequence of rows of equal lengths.
+    pub fn deserialize<'de, M, I, D>(deserializer: D) -> Result<Grid1D<M, I, Vec<I>>, D::Error>
+    where
+        M: Major,
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let rows = Vec::<Vec<I>>::deserialize(deserializer)?;
+        let x = rows.first().map_or(0, Vec::len);
+        let y = rows.len();
+
+        if let Some(row) = rows.iter().find(|row| row.len() != x) {
+            return Err(D::Error::invalid_length(
+                row.len(),
+                &format!("rows of {} items", x).as_str(),
+            ));
+        }
+
+        let mut items = Vec::with_capacity(x * y);
+        if is_row_major::<M>() {
+            // Rows are contiguous
+            rows.into_iter().for_each(|row| items.extend(row));
+        } else {
+            let mut rows = rows.into_iter().map(Vec::into_iter).collect::<Vec<_>>();
+
+            for _ in 0..x {
+                items.extend(rows.iter_mut().filter_map(Iterator::next));
+            }
+        }
+
+        Ok(Grid1D::new_unchecked(Size { x, y }, items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::{from_str, json, to_value};
+    use std::{string::ToString, vec};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Board {
+        #[serde(with = "rows")]
+        cols: ColGrid1D<u8, Vec<u8>>,
+        #[serde(with = "rows")]
+        rows: RowGrid1D<u8, Vec<u8>>,
+    }
+
+    #[test]
+    fn grid1d() {
+        let grid = ColGrid1D::new((3, 2).into(), vec![1u8, 4, 2, 5, 3, 6]).unwrap();
+        let value = json!({ "size": { "x": 3, "y": 2 }, "items": [1, 4, 2, 5, 3, 6] });
+
+        assert_eq!(to_value(&grid).unwrap(), value);
+        assert_eq!(
+            from_str::<ColGrid1D<u8, Vec<u8>>>(&value.to_string()).unwrap(),
+            grid
+        );
+
+        let err = from_str::<RowGrid1D<u8, Vec<u8>>>(r#"{"size":{"x":2,"y":2},"items":[1]}"#);
+        assert!(err.unwrap_err().to_string().contains("2x2 items"));
+
+        let err = from_str::<RowGrid1D<u8, Vec<u8>>>(
+            &json!({ "size": { "x": usize::MAX, "y": 2 }, "items": [] }).to_string(),
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn rows() {
+        let board = Board {
+            cols: ColGrid1D::new((3, 2).into(), vec![1, 4, 2, 5, 3, 6]).unwrap(),
+            rows: RowGrid1D::new((3, 2).into(), vec![1, 2, 3, 4, 5, 6]).unwrap(),
+        };
+        let value = json!({ "cols": [[1, 2, 3], [4, 5, 6]], "rows": [[1, 2, 3], [4, 5, 6]] });
+
+        assert_eq!(to_value(&board).unwrap(), value);
+        assert_eq!(from_str::<Board>(&value.to_string()).unwrap(), board);
+
+        let ragged = json!({ "cols": [[1, 2], [3]], "rows": [] });
+        assert!(from_str::<Board>(&ragged.to_string()).is_err());
+    }
+
+    #[test]
+    fn others() {
+        assert_eq!(
+            to_value(Point { x: 1, y: 2 }).unwrap(),
+            json!({ "x": 1, "y": 2 })
+        );
+        assert_eq!(
+            from_str::<RowMajor>(r#"{"x":1,"y":2}"#).unwrap(),
+            RowMajor { x: 1, y: 2 }
+        );
+        assert_eq!(
+            from_str::<Repeat<char>>(&to_value(repeat((1, 2).into(), 'a')).unwrap().to_string())
+                .unwrap(),
+            repeat((1, 2).into(), 'a')
+        );
+    }
+}
@@ -0,0 +1,310 @@
+//! [`image`](::image) integration.
+//!
+//! [`ImageBuffer`](::image::ImageBuffer)s are grids when wrapped in an
+//! [`ImageView`](ImageView) (row-major, items being `&P`), and
+//! [`to_image`](to_image) collects any [`GridRows`](crate::GridRows) of
+//! pixels into an [`ImageBuffer`](::image::ImageBuffer).
+//!
+//! The grid traits cannot be implemented on `&ImageBuffer` directly, since
+//! [`WithSize`](crate::WithSize) is implemented for all
+//! [`Deref`](core::ops::Deref) types.
+
+use crate::*;
+use ::image::{ImageBuffer, Pixel};
+use std::{
+    convert::TryFrom,
+    iter::Flatten,
+    marker::PhantomData,
+    ops::{Deref, Range},
+    vec::Vec,
+};
+
+/// An [`ImageBuffer`](::image::ImageBuffer) as a (row-major) grid of `&P`.
+pub struct ImageView<'a, P: Pixel, C>(pub &'a ImageBuffer<P, C>);
+
+impl<'a, P: Pixel, C> Copy for ImageView<'a, P, C> {}
+
+impl<'a, P: Pixel, C> Clone for ImageView<'a, P, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, P: Pixel, C> From<&'a ImageBuffer<P, C>> for ImageView<'a, P, C> {
+    fn from(image: &'a ImageBuffer<P, C>) -> Self {
+        Self(image)
+    }
+}
+
+impl<'a, P, C> ImageView<'a, P, C>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    /// Returns `count` pixels from `point`, `step` pixels apart.
+    fn pixels(self, point: Point, step: usize, count: usize) -> Pixels<'a, P> {
+        let channels = usize::from(P::CHANNEL_COUNT);
+        let subpixels: &'a [P::Subpixel] = self.0;
+        let subpixels = if count == 0 {
+            &[]
+        } else {
+            &subpixels[(point.y * self.0.width() as usize + point.x) * channels..]
+        };
+
+        Pixels {
+            subpixels,
+            step: step * channels,
+            count,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, P, C> WithSize for ImageView<'a, P, C>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    fn size(&self) -> Size {
+        let (x, y) = self.0.dimensions();
+
+        Size {
+            x: x as usize,
+            y: y as usize,
+        }
+    }
+}
+
+impl<'a, P, C> Grid for ImageView<'a, P, C>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    type Item = &'a P;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Point { x, y } = index.unchecked();
+
+        self.0.get_pixel(x as u32, y as u32)
+    }
+}
+
+impl<'a, P, C> GridRow for ImageView<'a, P, C>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    type Row = Pixels<'a, P>;
+
+    unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+        let (y, Range { start, end }) = index.row_unchecked(self.size());
+
+        self.pixels(Point { x: start, y }, 1, end - start)
+    }
+}
+
+impl<'a, P, C> GridCol for ImageView<'a, P, C>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    type Col = Pixels<'a, P>;
+
+    unsafe fn col_unchecked(self, index: impl Index1D) -> Self::Col {
+        let (x, Range { start, end }) = index.col_unchecked(self.size());
+
+        self.pixels(Point { x, y: start }, self.size().x, end - start)
+    }
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident $lines:ident $range:ident $rows:literal)*) => { $(
+        impl<'a, P, C> $Trait for ImageView<'a, P, C>
+        where
+            P: Pixel,
+            C: Deref<Target = [P::Subpixel]>,
+        {
+            type $Assoc = Lines<'a, P, C>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let index = index.unchecked(self.size());
+
+                Lines {
+                    view:  self,
+                    lines: index.$lines,
+                    range: index.$range,
+                    rows:  $rows,
+                }
+            }
+        }
+    )* };
+}
+
+grid2d!(
+    GridRows Rows rows_unchecked y x true
+    GridCols Cols cols_unchecked x y false
+);
+
+impl<'a, P, C> GridItems for ImageView<'a, P, C>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    type Items = Flatten<Lines<'a, P, C>>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+/// Iterator over pixels of a row or column of an [`ImageView`](ImageView).
+pub struct Pixels<'a, P: Pixel> {
+    subpixels: &'a [P::Subpixel],
+    step:      usize,
+    count:     usize,
+    phantom:   PhantomData<P>,
+}
+
+impl<'a, P: Pixel + 'a> Iterator for Pixels<'a, P> {
+    type Item = &'a P;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            None
+        } else {
+            let pixel = P::from_slice(&self.subpixels[..usize::from(P::CHANNEL_COUNT)]);
+
+            self.count -= 1;
+            if self.count != 0 {
+                self.subpixels = &self.subpixels[self.step..];
+            }
+
+            Some(pixel)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+}
+
+impl<'a, P: Pixel + 'a> ExactSizeIterator for Pixels<'a, P> {}
+
+/// Iterator over the rows or columns of an [`ImageView`](ImageView).
+pub struct Lines<'a, P: Pixel, C> {
+    view:  ImageView<'a, P, C>,
+    lines: Range<usize>,
+    range: Range<usize>,
+    rows:  bool,
+}
+
+impl<'a, P, C> Iterator for Lines<'a, P, C>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    type Item = Pixels<'a, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.lines.next()?;
+        let index = (i, self.range.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe {
+            if self.rows {
+                self.view.row_unchecked(index)
+            } else {
+                self.view.col_unchecked(index)
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+/// Collects `grid` into an [`ImageBuffer`](::image::ImageBuffer), or returns
+/// [`None`](core::option::Option::None) if its size does not fit in `u32`s.
+pub fn to_image<P, G>(grid: G) -> Option<ImageBuffer<P, Vec<P::Subpixel>>>
+where
+    P: Pixel,
+    G: GridRows,
+    G::Item: Into<P>,
+{
+    let size = grid.size();
+    let (width, height) = (u32::try_from(size.x).ok()?, u32::try_from(size.y).ok()?);
+    let mut subpixels = Vec::with_capacity(size.x * size.y * usize::from(P::CHANNEL_COUNT));
+
+    // SAFETY: RangeFull is always in bounds
+    for row in unsafe { grid.rows_unchecked(..) } {
+        for item in row {
+            subpixels.extend_from_slice(item.into().channels());
+        }
+    }
+
+    ImageBuffer::from_raw(width, height, subpixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::image::{GenericImageView, GrayImage, Luma, Rgb, RgbImage};
+    use pretty_assertions::assert_eq;
+    use std::vec;
+
+    #[test]
+    fn image_view() {
+        let image = GrayImage::from_fn(4, 3, |x, y| Luma([(10 * y + x) as u8]));
+        let view = ImageView(&image);
+        let luma = |pixels: Pixels<Luma<u8>>| pixels.map(|pixel| pixel.0[0]).collect::<Vec<_>>();
+
+        assert_eq!(view.size(), Size { x: 4, y: 3 });
+        assert_eq!(view.item((3, 2)), Some(&Luma([23])));
+        assert_eq!(view.item((4, 2)), None);
+        assert_eq!(luma(view.row((1, 1..)).unwrap()), [11, 12, 13]);
+        assert_eq!(luma(view.col((2, ..)).unwrap()), [2, 12, 22]);
+        assert_eq!(luma(view.col((3, 3..)).unwrap()), Vec::<u8>::new());
+
+        let cropped = view.cropped((1..3, 1..)).unwrap();
+        assert_eq!(
+            cropped
+                .clone()
+                .cols(..)
+                .unwrap()
+                .map(luma)
+                .collect::<Vec<_>>(),
+            [[11, 21], [12, 22]]
+        );
+        assert_eq!(
+            cropped
+                .clone()
+                .items(..)
+                .unwrap()
+                .map(|pixel| pixel.0[0])
+                .collect::<Vec<_>>(),
+            [11, 12, 21, 22]
+        );
+        assert_eq!(
+            to_image(cropped.copied()).unwrap(),
+            image.view(1, 1, 2, 2).to_image()
+        );
+    }
+
+    #[test]
+    fn grid_to_image() {
+        let grid = RowGrid1D::new((2, 1).into(), vec![[1, 2, 3], [4, 5, 6]]).unwrap();
+        let image: RgbImage = to_image((&grid).copied()).unwrap();
+
+        assert_eq!(image.dimensions(), (2, 1));
+        assert_eq!(image.get_pixel(1, 0), &Rgb([4, 5, 6]));
+
+        let grid = ColGrid1D::new((2, 2).into(), vec![[0u8], [1], [2], [3]]).unwrap();
+        assert_eq!(
+            to_image::<Luma<u8>, _>((&grid).copied())
+                .unwrap()
+                .into_raw(),
+            [0, 2, 1, 3]
+        );
+    }
+}
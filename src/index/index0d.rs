@@ -5,17 +5,25 @@ use crate::*;
 /// The underlying type to index an item is [`Point`](Point).
 ///
 /// Anything that `Into<Point>` is an [`Index0D`](Index0D).
-pub trait Index0D {
+pub trait Index0D: Sized {
     /// Returns the index as a [`Point`](Point), without bounds checking.
     fn unchecked(self) -> Point;
 
     /// Returns the index as a [`Point`](Point), or
-    /// [`None`](std::option::Option::None) if out of bounds.
+    /// [`None`](core::option::Option::None) if out of bounds.
     ///
     /// When `Some`, guaranties:
     /// - `point.x < size.x`
     /// - `point.y < size.y`
-    fn checked(self, size: Size) -> Option<Point>;
+    fn checked(self, size: Size) -> Option<Point> {
+        self.try_checked(size).ok()
+    }
+
+    /// Returns the index as a [`Point`](Point), or a
+    /// [`GridError`](GridError) if out of bounds.
+    ///
+    /// When `Ok`, guaranties the same as [`checked`](Index0D::checked).
+    fn try_checked(self, size: Size) -> Result<Point, GridError>;
 }
 
 impl<T: Into<Point>> Index0D for T {
@@ -23,13 +31,19 @@ impl<T: Into<Point>> Index0D for T {
         self.into()
     }
 
-    fn checked(self, size: Size) -> Option<Point> {
+    fn try_checked(self, size: Size) -> Result<Point, GridError> {
         let point = self.into();
 
         if point < size {
-            Some(point)
+            Ok(point)
         } else {
-            None
+            Err(GridError::OutOfBounds {
+                index: Rect {
+                    x: point.x..point.x.saturating_add(1),
+                    y: point.y..point.y.saturating_add(1),
+                },
+                size,
+            })
         }
     }
 }
@@ -1,52 +1,63 @@
 use crate::*;
-use std::ops::{Range, RangeBounds};
+use core::ops::{Range, RangeBounds};
 
 /// Indexes for [`GridCol::col`](GridCol::col) /
 /// [`GridRow::row`](GridRow::row).
 ///
 /// The underlying type to index a column/row is `(usize, Range<usize>)`, with:
 /// - [`usize`](usize): the index of the column/row,
-/// - [`Range<usize>`](std::ops::Range): the range of items in that column/row.
+/// - [`Range<usize>`](core::ops::Range): the range of items in that column/row.
 ///
-/// `usize` (implied [`RangeFull`](std::ops::RangeFull)) and `(usize, T:
+/// `usize` (implied [`RangeFull`](core::ops::RangeFull)) and `(usize, T:
 /// RangeBounds<usize>)` are [`Index1D`](Index1D)s.
 pub trait Index1D: Sized {
     /// Returns the index as `(usize, Range<usize>)`, without bounds checking.
     ///
-    /// [`Unbounded`](std::ops::Bound::Unbounded) start/end bounds will
+    /// [`Unbounded`](core::ops::Bound::Unbounded) start/end bounds will
     /// transform into `0`/`max_end`.  
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds may overflow.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds may overflow.
     fn unchecked(self, max_end: usize) -> (usize, Range<usize>);
 
     /// Returns the index as `(usize, Range<usize>)`, or
-    /// [`None`](std::option::Option::None) if out of bounds.
+    /// [`None`](core::option::Option::None) if out of bounds.
     ///
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds saturate.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds saturate.
     ///
     /// When `Some`, guaranties:
     /// - `usize < max_i`
     /// - `range.start <= range.end`
     /// - `range.end <= end`
-    fn checked(self, max_i: usize, max_end: usize) -> Option<(usize, Range<usize>)>;
+    fn checked(self, max_i: usize, max_end: usize) -> Option<(usize, Range<usize>)> {
+        self.try_checked(max_i, max_end).ok()
+    }
+
+    /// Returns the index as `(usize, Range<usize>)`, or a
+    /// [`GridError`](GridError) if out of bounds.
+    ///
+    /// [`OutOfBounds`](GridError::OutOfBounds) errors are reported as for a
+    /// row (`usize` on the `y` axis, of length `max_i`).
+    ///
+    /// When `Ok`, guaranties the same as [`checked`](Index1D::checked).
+    fn try_checked(self, max_i: usize, max_end: usize) -> Result<(usize, Range<usize>), GridError>;
 
     /// Returns the column index as `(usize, Range<usize>)`, without bounds
     /// checking.
     ///
-    /// [`Unbounded`](std::ops::Bound::Unbounded) start/end bounds will
+    /// [`Unbounded`](core::ops::Bound::Unbounded) start/end bounds will
     /// transform into `0`/`max_end`.  
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds may overflow.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds may overflow.
     fn col_unchecked(self, size: Size) -> (usize, Range<usize>) {
         self.unchecked(size.y)
     }
 
     /// Returns the column index as `(usize, Range<usize>)`, or
-    /// [`None`](std::option::Option::None) if out of bounds.
+    /// [`None`](core::option::Option::None) if out of bounds.
     ///
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds saturate.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds saturate.
     ///
     /// When `Some`, guaranties:
     /// - `usize < size.x`
@@ -57,22 +68,31 @@ pub trait Index1D: Sized {
         self.checked(size.x, size.y)
     }
 
+    /// Returns the column index as `(usize, Range<usize>)`, or a
+    /// [`GridError`](GridError) if out of bounds.
+    ///
+    /// When `Ok`, guaranties the same as [`col`](Index1D::col).
+    fn try_col(self, size: Size) -> Result<(usize, Range<usize>), GridError> {
+        self.try_checked(size.x, size.y)
+            .map_err(GridError::transposed)
+    }
+
     /// Returns the row index as `(usize, Range<usize>)`, without bounds
     /// checking.
     ///
-    /// [`Unbounded`](std::ops::Bound::Unbounded) start/end bounds will
+    /// [`Unbounded`](core::ops::Bound::Unbounded) start/end bounds will
     /// transform into `0`/`max_end`.  
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds may overflow.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds may overflow.
     fn row_unchecked(self, size: Size) -> (usize, Range<usize>) {
         self.unchecked(size.x)
     }
 
     /// Returns the row index as `(usize, Range<usize>)`, or
-    /// [`None`](std::option::Option::None) if out of bounds.
+    /// [`None`](core::option::Option::None) if out of bounds.
     ///
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds saturate.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds saturate.
     ///
     /// When `Some`, guaranties:
     /// - `usize < size.y`
@@ -81,6 +101,14 @@ pub trait Index1D: Sized {
     fn row(self, size: Size) -> Option<(usize, Range<usize>)> {
         self.checked(size.y, size.x)
     }
+
+    /// Returns the row index as `(usize, Range<usize>)`, or a
+    /// [`GridError`](GridError) if out of bounds.
+    ///
+    /// When `Ok`, guaranties the same as [`row`](Index1D::row).
+    fn try_row(self, size: Size) -> Result<(usize, Range<usize>), GridError> {
+        self.try_checked(size.y, size.x)
+    }
 }
 
 impl Index1D for usize {
@@ -88,12 +116,8 @@ impl Index1D for usize {
         (self, 0..max_end)
     }
 
-    fn checked(self, max_i: usize, max_end: usize) -> Option<(usize, Range<usize>)> {
-        if self < max_i {
-            Some(self.unchecked(max_end))
-        } else {
-            None
-        }
+    fn try_checked(self, max_i: usize, max_end: usize) -> Result<(usize, Range<usize>), GridError> {
+        (self, ..).try_checked(max_i, max_end)
     }
 }
 
@@ -102,13 +126,23 @@ impl<T: RangeBounds<usize>> Index1D for (usize, T) {
         (self.0, ToRange::unchecked(self.1, max_end))
     }
 
-    fn checked(self, max_i: usize, max_end: usize) -> Option<(usize, Range<usize>)> {
+    fn try_checked(self, max_i: usize, max_end: usize) -> Result<(usize, Range<usize>), GridError> {
         let (i, range) = self;
+        let range = range.resolved(max_end)?;
 
-        if i < max_i {
-            Some((i, ToRange::checked(range, max_end)?))
+        if i < max_i && range.end <= max_end {
+            Ok((i, range))
         } else {
-            None
+            Err(GridError::OutOfBounds {
+                index: Rect {
+                    x: range,
+                    y: i..i.saturating_add(1),
+                },
+                size:  Size {
+                    x: max_end,
+                    y: max_i,
+                },
+            })
         }
     }
 }
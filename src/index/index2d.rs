@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::RangeBounds;
+use core::ops::RangeBounds;
 
 /// Indexes for [`GridCols::cols`](GridCols::cols) /
 /// [`GridRows::rows`](GridRows::rows) /
@@ -8,31 +8,39 @@ use std::ops::RangeBounds;
 /// The underlying type to index columns/rows/items is [`Rect`](Rect)
 /// (`Coord<Range<usize>>`).
 ///
-/// [`RangeFull`](std::ops::RangeFull) (implied on both axis), `Coord<X:
+/// [`RangeFull`](core::ops::RangeFull) (implied on both axis), `Coord<X:
 /// RangeBounds<usize>, Y: RangeBounds<usize>>` and `(X: RangeBounds<usize>, Y:
 /// RangeBounds<usize>)` are [`Index2D`](Index2D)s.
-pub trait Index2D {
+pub trait Index2D: Sized {
     /// Returns the index as a [`Rect`](Rect), without bounds checking.
     ///
-    /// [`Unbounded`](std::ops::Bound::Unbounded) start/end bounds will
+    /// [`Unbounded`](core::ops::Bound::Unbounded) start/end bounds will
     /// transform into `0`/`size`.  
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds may overflow.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds may overflow.
     fn unchecked(self, size: Size) -> Rect;
 
     /// Returns the index as [`Rect`](Rect), or
-    /// [`None`](std::option::Option::None) if out of bounds.
+    /// [`None`](core::option::Option::None) if out of bounds.
     ///
-    /// [`Excluded`](std::ops::Bound::Excluded) start bounds and
-    /// [`Included`](std::ops::Bound::Included) end bounds saturate.
+    /// [`Excluded`](core::ops::Bound::Excluded) start bounds and
+    /// [`Included`](core::ops::Bound::Included) end bounds saturate.
     ///
     /// When `Some`, guaranties on both axis:
     /// - `start <= end`
     /// - `end <= len`
-    fn checked(self, size: Size) -> Option<Rect>;
+    fn checked(self, size: Size) -> Option<Rect> {
+        self.try_checked(size).ok()
+    }
+
+    /// Returns the index as [`Rect`](Rect), or a [`GridError`](GridError)
+    /// if out of bounds.
+    ///
+    /// When `Ok`, guaranties the same as [`checked`](Index2D::checked).
+    fn try_checked(self, size: Size) -> Result<Rect, GridError>;
 }
 
-impl Index2D for std::ops::RangeFull {
+impl Index2D for core::ops::RangeFull {
     fn unchecked(self, size: Size) -> Rect {
         Point {
             x: 0..size.x,
@@ -40,8 +48,8 @@ impl Index2D for std::ops::RangeFull {
         }
     }
 
-    fn checked(self, size: Size) -> Option<Rect> {
-        Some(Index2D::unchecked(self, size))
+    fn try_checked(self, size: Size) -> Result<Rect, GridError> {
+        Ok(Index2D::unchecked(self, size))
     }
 }
 
@@ -50,8 +58,8 @@ impl<X: RangeBounds<usize>, Y: RangeBounds<usize>> Index2D for Coord<X, Y> {
         (self.x, self.y).unchecked(size)
     }
 
-    fn checked(self, size: Size) -> Option<Rect> {
-        (self.x, self.y).checked(size)
+    fn try_checked(self, size: Size) -> Result<Rect, GridError> {
+        (self.x, self.y).try_checked(size)
     }
 }
 
@@ -63,10 +71,16 @@ impl<X: RangeBounds<usize>, Y: RangeBounds<usize>> Index2D for (X, Y) {
         }
     }
 
-    fn checked(self, size: Size) -> Option<Rect> {
-        Some(Point {
-            x: ToRange::checked(self.0, size.x)?,
-            y: ToRange::checked(self.1, size.y)?,
-        })
+    fn try_checked(self, size: Size) -> Result<Rect, GridError> {
+        let index = Point {
+            x: self.0.resolved(size.x)?,
+            y: self.1.resolved(size.y)?,
+        };
+
+        if index.x.end <= size.x && index.y.end <= size.y {
+            Ok(index)
+        } else {
+            Err(GridError::OutOfBounds { index, size })
+        }
     }
 }
@@ -0,0 +1,87 @@
+//! Common convolution kernels, for [`convolve`](crate::convolve()).
+
+use crate::*;
+use std::{vec, vec::Vec};
+
+/// A convolution kernel.
+pub type Kernel = RowGrid1D<f64, Vec<f64>>;
+
+/// Creates a normalized box blur kernel of `size`.
+pub fn box_blur(size: Size) -> Kernel {
+    let len = size.x * size.y;
+
+    RowGrid1D::new_unchecked(size, vec![1. / len as f64; len])
+}
+
+/// Creates a normalized gaussian blur kernel of size `2 * radius + 1` with
+/// standard deviation `sigma`.
+pub fn gaussian(radius: usize, sigma: f64) -> Kernel {
+    let side = 2 * radius + 1;
+    let weights = (0..side)
+        .map(|i| {
+            let d = i as f64 - radius as f64;
+
+            (-d * d / (2. * sigma * sigma)).exp()
+        })
+        .collect::<Vec<_>>();
+    let mut items = Vec::with_capacity(side * side);
+
+    for wy in &weights {
+        for wx in &weights {
+            items.push(wx * wy);
+        }
+    }
+
+    let sum = items.iter().sum::<f64>();
+    items.iter_mut().for_each(|item| *item /= sum);
+
+    RowGrid1D::new_unchecked(Size { x: side, y: side }, items)
+}
+
+/// Creates the 3x3 horizontal Sobel kernel (responds to vertical edges).
+pub fn sobel_x() -> Kernel {
+    #[rustfmt::skip]
+    let items = vec![
+        -1., 0., 1.,
+        -2., 0., 2.,
+        -1., 0., 1.,
+    ];
+
+    RowGrid1D::new_unchecked(Size { x: 3, y: 3 }, items)
+}
+
+/// Creates the 3x3 vertical Sobel kernel (responds to horizontal edges).
+pub fn sobel_y() -> Kernel {
+    #[rustfmt::skip]
+    let items = vec![
+        -1., -2., -1.,
+         0.,  0.,  0.,
+         1.,  2.,  1.,
+    ];
+
+    RowGrid1D::new_unchecked(Size { x: 3, y: 3 }, items)
+}
+
+/// Creates the 3x3 (4-connected) Laplacian kernel.
+pub fn laplacian() -> Kernel {
+    #[rustfmt::skip]
+    let items = vec![
+        0.,  1., 0.,
+        1., -4., 1.,
+        0.,  1., 0.,
+    ];
+
+    RowGrid1D::new_unchecked(Size { x: 3, y: 3 }, items)
+}
+
+/// Creates the 3x3 sharpen kernel.
+pub fn sharpen() -> Kernel {
+    #[rustfmt::skip]
+    let items = vec![
+         0., -1.,  0.,
+        -1.,  5., -1.,
+         0., -1.,  0.,
+    ];
+
+    RowGrid1D::new_unchecked(Size { x: 3, y: 3 }, items)
+}
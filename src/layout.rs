@@ -0,0 +1,205 @@
+use crate::Coord;
+use core::convert::TryFrom;
+
+/// Encodes the memory layout of [`Grid1D`](crate::Grid1D)s in
+/// [`Size`](crate::Size)s types.
+///
+/// [`Major`](crate::Major)s ([`RowMajor`](crate::RowMajor) /
+/// [`ColMajor`](crate::ColMajor)) have contiguous rows or columns, other
+/// layouts ([`MortonOrder`](MortonOrder) / [`Tiled`](Tiled)) keep nearby
+/// items close in memory.
+pub trait Layout: Copy + From<Coord> + Into<Coord> {
+    /// Returns the length of the buffer of a grid of this size (padding
+    /// included), or [`None`](core::option::Option::None) on overflow.
+    fn capacity(self) -> Option<usize>;
+
+    /// Returns the index of the item at `point` in a grid of this size.
+    ///
+    /// `point` is assumed in bounds.
+    fn index(self, point: Coord) -> usize;
+}
+
+/// A [`Size`](crate::Size) for Z-order (Morton) grids.
+///
+/// Items are ordered by interleaving the bits of their coordinates (`x` in
+/// even bits, `y` in odd bits). Grids have room for all the indexes up to
+/// the last item's, which fits best square sizes with power of two sides.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MortonOrder {
+    /// The size on the x axis.
+    pub x: usize,
+    /// The size on the y axis.
+    pub y: usize,
+}
+
+/// A [`Size`](crate::Size) for grids of `W * H` row-major tiles, themselves
+/// in row-major order.
+///
+/// Grids have room for whole tiles: `⌈x / W⌉ * W` by `⌈y / H⌉ * H` items.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tiled<const W: usize, const H: usize> {
+    /// The size on the x axis.
+    pub x: usize,
+    /// The size on the y axis.
+    pub y: usize,
+}
+
+macro_rules! coord {
+    ($([$($generics:tt)*] $Layout:ty)*) => { $(
+        impl<$($generics)*> From<Coord> for $Layout {
+            fn from(Coord { x, y }: Coord) -> Self {
+                Self { x, y }
+            }
+        }
+
+        impl<$($generics)*> From<$Layout> for Coord {
+            fn from(size: $Layout) -> Self {
+                Self { x: size.x, y: size.y }
+            }
+        }
+    )* };
+}
+
+coord!(
+    [] MortonOrder
+    [const W: usize, const H: usize] Tiled<W, H>
+);
+
+impl MortonOrder {
+    /// Spreads the (32 lowest) bits of `n` to even bits.
+    fn spread(n: usize) -> u64 {
+        let mut n = n as u64 & 0xffff_ffff;
+
+        n = (n | n << 16) & 0x0000_ffff_0000_ffff;
+        n = (n | n << 8) & 0x00ff_00ff_00ff_00ff;
+        n = (n | n << 4) & 0x0f0f_0f0f_0f0f_0f0f;
+        n = (n | n << 2) & 0x3333_3333_3333_3333;
+        (n | n << 1) & 0x5555_5555_5555_5555
+    }
+}
+
+impl Layout for MortonOrder {
+    fn capacity(self) -> Option<usize> {
+        let max = u32::MAX as usize;
+
+        match (self.x.checked_sub(1), self.y.checked_sub(1)) {
+            (Some(x), Some(y)) if x <= max && y <= max => {
+                let last = Self::spread(x) | Self::spread(y) << 1;
+
+                usize::try_from(last).ok()?.checked_add(1)
+            }
+            (Some(_), Some(_)) => None,
+            _ => Some(0),
+        }
+    }
+
+    fn index(self, Coord { x, y }: Coord) -> usize {
+        (Self::spread(x) | Self::spread(y) << 1) as usize
+    }
+}
+
+impl<const W: usize, const H: usize> Layout for Tiled<W, H> {
+    fn capacity(self) -> Option<usize> {
+        if W == 0 || H == 0 {
+            return None;
+        }
+
+        let x = self.x.div_ceil(W).checked_mul(W)?;
+        let y = self.y.div_ceil(H).checked_mul(H)?;
+
+        x.checked_mul(y)
+    }
+
+    fn index(self, Coord { x, y }: Coord) -> usize {
+        let tiles = self.x.div_ceil(W);
+        let tile = (y / H) * tiles + x / W;
+
+        (tile * H + y % H) * W + x % W
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use pretty_assertions::assert_eq;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn morton() {
+        let layout = MortonOrder { x: 3, y: 2 };
+        let index = |x, y| layout.index(Coord { x, y });
+
+        assert_eq!(layout.capacity(), Some(7));
+        assert_eq!([index(0, 0), index(1, 0), index(0, 1), index(1, 1)], [
+            0, 1, 2, 3
+        ]);
+        assert_eq!([index(2, 0), index(2, 1)], [4, 6]);
+        assert_eq!(MortonOrder { x: 0, y: 5 }.capacity(), Some(0));
+
+        let grid = MortonGrid1D::new((3, 2).into(), (0..7).collect::<Vec<_>>()).unwrap();
+        assert!(MortonGrid1D::new((3, 2).into(), vec![0; 6]).is_none());
+
+        assert_eq!(grid.item((2, 1)), Some(&6));
+        assert_eq!(grid.row(1).unwrap().copied().collect::<Vec<_>>(), [2, 3, 6]);
+        assert_eq!(grid.col((2, 1..)).unwrap().copied().collect::<Vec<_>>(), [
+            6
+        ]);
+        assert_eq!(grid.items(..).unwrap().copied().collect::<Vec<_>>(), [
+            0, 1, 4, 2, 3, 6
+        ]);
+        assert_eq!(
+            grid.cols((1.., ..))
+                .unwrap()
+                .rev()
+                .map(|col| col.rev().copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [[6, 4], [3, 1]]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tiled() {
+        use std::format;
+
+        let layout = Tiled::<2, 2> { x: 3, y: 3 };
+        let index = |x, y| layout.index(Coord { x, y });
+
+        assert_eq!(layout.capacity(), Some(16));
+        assert_eq!([index(0, 0), index(1, 0), index(0, 1), index(1, 1)], [
+            0, 1, 2, 3
+        ]);
+        assert_eq!([index(2, 0), index(0, 2), index(2, 2)], [4, 8, 12]);
+        assert_eq!(Tiled::<0, 2> { x: 3, y: 3 }.capacity(), None);
+
+        let mut grid = TiledGrid1D::<_, _, 2, 2>::new((3, 3).into(), vec![0; 16]).unwrap();
+        for (y, mut row) in (&mut grid).rows(..).unwrap().enumerate() {
+            for (x, item) in row.by_ref().enumerate() {
+                *item = 10 * y + x;
+            }
+        }
+        for item in (&mut grid).col(2).unwrap() {
+            *item += 100;
+        }
+
+        assert_eq!(grid.as_ref(), [
+            0, 1, 10, 11, 102, 0, 112, 0, 20, 21, 0, 0, 122, 0, 0, 0
+        ]);
+        assert_eq!(grid.row((2, 1..)).unwrap().copied().collect::<Vec<_>>(), [
+            21, 122
+        ]);
+        assert_eq!(
+            format!("{:?}", grid),
+            [
+                "  │  0  1   2",
+                "──┼──────────",
+                "0 │  0  1 102",
+                "1 │ 10 11 112",
+                "2 │ 20 21 122",
+            ]
+            .join("\n")
+        );
+    }
+}
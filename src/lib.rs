@@ -1,28 +1,129 @@
 // TODO: tests, docs, examples
 // #![warn(missing_docs)]
 
+//! ### Features
+//!
+//! The crate is `no_std`: grid traits, indexes, [`Grid1D`](Grid1D) over
+//! borrowed slices and non-allocating adapters are always available.
+//!
+//! - `alloc`: `Vec`-based owned grids and algorithms (automata, diffs, text,
+//!   terminal cells, ...),
+//! - `std` (default): implies `alloc`, plus I/O (codecs, csv, netpbm,
+//!   terminals) and floating-point based algorithms (filters, distances,
+//!   summed-area tables),
+//! - `image`, `ndarray`, `rayon`, `serde`: integrations, implying `std`.
+//!
+//! `cargo check --no-default-features` checks the `no_std` build (tests link
+//! `std` for the test harness only).
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "std")]
+pub mod ansi;
+#[cfg(feature = "alloc")]
+pub mod cell;
+#[cfg(feature = "std")]
+pub mod codec;
+pub mod framebuffer;
 pub mod grid1d;
+#[cfg(feature = "image")]
+pub mod image;
+#[cfg(feature = "std")]
+pub mod kernel;
+#[cfg(feature = "std")]
+pub mod netpbm;
 pub mod repeat;
 pub mod repeat_with;
+#[cfg(feature = "std")]
+pub mod vt;
 
-pub use grid1d::{ColGrid1D, Grid1D, RowGrid1D};
+#[cfg(feature = "image")]
+pub use self::image::ImageView;
+#[cfg(feature = "std")]
+pub use ansi::Renderer;
+#[cfg(feature = "alloc")]
+pub use cell::{Cell, Style, TermGrid};
+pub use framebuffer::Framebuffer;
+#[cfg(feature = "ndarray")]
+pub use grid1d::ndarray::NdView;
+pub use grid1d::{ColGrid1D, Grid1D, MortonGrid1D, RowGrid1D, TiledGrid1D};
 pub use repeat::{repeat, Repeat};
 pub use repeat_with::RepeatWith;
+#[cfg(feature = "std")]
+pub use vt::Terminal;
 
+mod array;
+#[cfg(feature = "alloc")]
+mod automaton;
+#[cfg(feature = "alloc")]
+mod bit_grid;
 mod cloned;
 mod cropped;
+#[cfg(feature = "std")]
+mod csv;
+#[cfg(feature = "alloc")]
+mod debug;
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "std")]
+mod distance;
+mod error;
+#[cfg(feature = "std")]
+mod filter;
+#[cfg(feature = "alloc")]
+mod fov;
 mod grid;
 mod index;
+mod layout;
+mod line;
 mod major;
+mod neighbourhood;
+mod planar;
+mod strided;
+#[cfg(feature = "std")]
+mod summed_area;
+#[cfg(feature = "alloc")]
+mod text;
 mod utils;
 mod with_msize;
 mod with_size;
 
 pub use self::grid::*;
+pub use array::*;
+#[cfg(feature = "alloc")]
+pub use automaton::*;
+#[cfg(feature = "alloc")]
+pub use bit_grid::*;
 pub use cloned::*;
 pub use cropped::*;
+#[cfg(feature = "std")]
+pub use csv::*;
+#[cfg(feature = "alloc")]
+pub use debug::*;
+#[cfg(feature = "alloc")]
+pub use diff::*;
+#[cfg(feature = "std")]
+pub use distance::*;
+pub use error::*;
+#[cfg(feature = "std")]
+pub use filter::*;
+#[cfg(feature = "alloc")]
+pub use fov::*;
 pub use index::*;
+pub use layout::*;
+pub use line::*;
 pub use major::*;
+pub use neighbourhood::*;
+pub use planar::*;
+pub use strided::*;
+#[cfg(feature = "std")]
+pub use summed_area::*;
+#[cfg(feature = "alloc")]
+pub use text::*;
 pub use utils::*;
 pub use with_size::*;
 
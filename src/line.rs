@@ -0,0 +1,194 @@
+use crate::*;
+
+/// Creates an iterator over the [`Point`](Point)s of the line from `from` to
+/// `to` (both included), using Bresenham's algorithm.
+pub fn line(from: Point, to: Point) -> Line {
+    Line::new(from, to)
+}
+
+/// Creates an iterator over the items of `grid` on the line from `from` to
+/// `to` (both included).
+///
+/// The iterator stops at the first out-of-bounds point.
+pub fn line_items<G: Grid + Clone>(grid: G, from: Point, to: Point) -> LineItems<G> {
+    LineItems {
+        grid,
+        line: Line::new(from, to),
+    }
+}
+
+/// An iterator over the [`Point`](Point)s of a line.
+///
+/// This `struct` is created by [`line`](line()).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Line {
+    x:     isize,
+    y:     isize,
+    to:    (isize, isize),
+    delta: (isize, isize),
+    step:  (isize, isize),
+    error: isize,
+    done:  bool,
+}
+
+impl Line {
+    fn new(from: Point, to: Point) -> Self {
+        let (x, y) = (from.x as isize, from.y as isize);
+        let to = (to.x as isize, to.y as isize);
+
+        Self {
+            x,
+            y,
+            to,
+            delta: ((to.0 - x).abs(), -(to.1 - y).abs()),
+            step: ((to.0 - x).signum(), (to.1 - y).signum()),
+            error: (to.0 - x).abs() - (to.1 - y).abs(),
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Line {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let point = Point {
+            x: self.x as usize,
+            y: self.y as usize,
+        };
+
+        if (self.x, self.y) == self.to {
+            self.done = true;
+        } else {
+            let error = 2 * self.error;
+
+            if error >= self.delta.1 {
+                self.error += self.delta.1;
+                self.x += self.step.0;
+            }
+            if error <= self.delta.0 {
+                self.error += self.delta.0;
+                self.y += self.step.1;
+            }
+        }
+
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let len = (self.to.0 - self.x)
+                .unsigned_abs()
+                .max((self.to.1 - self.y).unsigned_abs())
+                + 1;
+
+            (len, Some(len))
+        }
+    }
+}
+
+impl ExactSizeIterator for Line {}
+impl core::iter::FusedIterator for Line {}
+
+/// An iterator over the items of a grid on a line.
+///
+/// This `struct` is created by [`line_items`](line_items()).
+#[derive(Clone, Debug)]
+pub struct LineItems<G> {
+    grid: G,
+    line: Line,
+}
+
+impl<G: Grid + Clone> Iterator for LineItems<G> {
+    type Item = G::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.line.next()?;
+
+        match self.grid.clone().item(point) {
+            Some(item) => Some(item),
+            None => {
+                self.line.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<G: Grid + Clone> core::iter::FusedIterator for LineItems<G> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn points(points: &[(usize, usize)]) -> impl Iterator<Item = Point> + '_ {
+        points.iter().map(|&point| point.into())
+    }
+
+    #[test]
+    fn lines() {
+        // Shallow
+        assert!(line((0, 0).into(), (4, 2).into()).eq(points(&[
+            (0, 0),
+            (1, 1),
+            (2, 1),
+            (3, 2),
+            (4, 2)
+        ])));
+
+        // Steep
+        assert!(line((1, 0).into(), (2, 4).into()).eq(points(&[
+            (1, 0),
+            (1, 1),
+            (2, 2),
+            (2, 3),
+            (2, 4)
+        ])));
+
+        // Reversed endpoints
+        assert!(line((4, 2).into(), (0, 0).into()).eq(points(&[
+            (4, 2),
+            (3, 1),
+            (2, 1),
+            (1, 0),
+            (0, 0)
+        ])));
+
+        // Zero-length
+        assert!(line((3, 3).into(), (3, 3).into()).eq(points(&[(3, 3)])));
+
+        let mut line = line((0, 5).into(), (3, 2).into());
+        assert_eq!(line.len(), 4);
+        line.next();
+        assert_eq!(line.len(), 3);
+        line.by_ref().for_each(drop);
+        assert_eq!((line.len(), line.next()), (0, None));
+    }
+
+    #[test]
+    fn items() {
+        let grid = RowGrid1D::new((3, 3).into(), [0, 1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+
+        assert!(line_items(&grid, (0, 0).into(), (2, 2).into())
+            .copied()
+            .eq([0, 4, 8]));
+        assert!(line_items(&grid, (2, 0).into(), (1, 2).into())
+            .copied()
+            .eq([2, 4, 7]));
+        assert!(line_items(&grid, (1, 1).into(), (1, 1).into())
+            .copied()
+            .eq([4]));
+
+        // It stops at the first out-of-bounds point
+        assert!(line_items(&grid, (1, 1).into(), (5, 1).into())
+            .copied()
+            .eq([4, 5]));
+    }
+}
@@ -1,11 +1,11 @@
-use crate::Coord;
+use crate::{Coord, Layout};
 
 /// Encodes memory layout in [`Size`](crate::Size)s types
 /// ([`RowMajor`](RowMajor) / [`ColMajor`](ColMajor)).
 ///
 /// A `Major` is a [`Size`](crate::Size) which knows its *major* and *minor*
 /// axis.
-pub trait Major: Copy + From<Coord> + Into<Coord> {
+pub trait Major: Layout {
     /// Returns a new `Self` from the lengths of the major axis `major`
     /// and minor axis `minor`.
     fn new(major: usize, minor: usize) -> Self;
@@ -25,6 +25,7 @@ macro_rules! majors {
     )*) => { $(
         $(#[$meta])*
         #[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $Major {
             $(#[$x_meta])*
             pub x: usize,
@@ -44,6 +45,18 @@ macro_rules! majors {
             }
         }
 
+        impl Layout for $Major {
+            fn capacity(self) -> Option<usize> {
+                self.x.checked_mul(self.y)
+            }
+
+            fn index(self, point: Coord) -> usize {
+                let point = Self::from(point);
+
+                point.minor() * self.major() + point.major()
+            }
+        }
+
         impl Major for $Major {
             fn new(major: usize, minor: usize) -> Self {
                 Self { $major: major, $minor: minor }
@@ -55,6 +68,11 @@ macro_rules! majors {
     )* };
 }
 
+/// Returns `true` if the major axis of `M` is x (rows are contiguous).
+pub(crate) fn is_row_major<M: Major>() -> bool {
+    M::new(1, 0).into().x == 1
+}
+
 majors!(
     /// A [`Size`](crate::Size) for column-major grids.
     ColMajor (y x)
@@ -0,0 +1,155 @@
+use crate::*;
+
+/// Which cells are adjacent to a cell.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Connectivity {
+    /// Orthogonal neighbours only (von Neumann neighbourhood).
+    Four,
+    /// Orthogonal and diagonal neighbours (Moore neighbourhood).
+    Eight,
+}
+
+impl Connectivity {
+    /// Returns the `(dx, dy)` offsets of the neighbours of a cell.
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        const FOUR: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+        const EIGHT: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+
+        match self {
+            Self::Four => &FOUR,
+            Self::Eight => &EIGHT,
+        }
+    }
+
+    /// Returns the in-bounds neighbours of `point` in a grid of `size`.
+    pub fn neighbours(self, point: Point, size: Size) -> impl Iterator<Item = Point> {
+        self.offsets().iter().filter_map(move |&(dx, dy)| {
+            let x = point.x.checked_add_signed(dx)?;
+            let y = point.y.checked_add_signed(dy)?;
+
+            if x < size.x && y < size.y {
+                Some(Point { x, y })
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// What lies beyond the edges of a grid.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EdgePolicy {
+    /// Nothing: out-of-bounds neighbours are missing (dead).
+    Dead,
+    /// The opposite edge: the grid is a torus.
+    Wrap,
+}
+
+impl EdgePolicy {
+    /// Returns the neighbour of `point` at `(dx, dy)` in a grid of `size`,
+    /// or [`None`](core::option::Option::None) if it does not exist.
+    pub fn neighbour(self, point: Point, (dx, dy): (isize, isize), size: Size) -> Option<Point> {
+        match self {
+            Self::Dead => {
+                let x = point.x.checked_add_signed(dx)?;
+                let y = point.y.checked_add_signed(dy)?;
+
+                if x < size.x && y < size.y {
+                    Some(Point { x, y })
+                } else {
+                    None
+                }
+            }
+            Self::Wrap =>
+                if size.x == 0 || size.y == 0 {
+                    None
+                } else {
+                    Some(Point {
+                        x: wrap(point.x, dx, size.x),
+                        y: wrap(point.y, dy, size.y),
+                    })
+                },
+        }
+    }
+}
+
+/// The eight (Moore) neighbours of a cell.
+///
+/// Neighbours are stored in row-major order, from the top-left (`(-1, -1)`)
+/// to the bottom-right (`(1, 1)`), as the [`offsets`](Connectivity::offsets)
+/// of [`Connectivity::Eight`](Connectivity::Eight). Missing neighbours are
+/// [`None`](core::option::Option::None).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Neighbourhood<T> {
+    cells: [Option<T>; 8],
+}
+
+impl<T> Neighbourhood<T> {
+    /// Creates a new `Neighbourhood` from its `cells`, in row-major order.
+    pub fn new(cells: [Option<T>; 8]) -> Self {
+        Self { cells }
+    }
+
+    /// Returns the neighbourhood of `point` in `grid`, with `edges`.
+    pub fn of<G: Grid<Item = T> + Clone>(grid: G, point: Point, edges: EdgePolicy) -> Self {
+        let size = grid.size();
+        let offsets = Connectivity::Eight.offsets();
+
+        Self::new(core::array::from_fn(|i| {
+            let point = edges.neighbour(point, offsets[i], size)?;
+
+            // SAFETY: neighbours are in bounds
+            Some(unsafe { grid.clone().item_unchecked(point) })
+        }))
+    }
+
+    /// Returns the cells, in row-major order.
+    pub fn cells(&self) -> &[Option<T>; 8] {
+        &self.cells
+    }
+
+    /// Returns the neighbour at `(dx, dy)` (each in `-1..=1`), or
+    /// [`None`](core::option::Option::None) if missing or `(0, 0)`.
+    pub fn get(&self, (dx, dy): (isize, isize)) -> Option<&T> {
+        let i = Connectivity::Eight
+            .offsets()
+            .iter()
+            .position(|offset| *offset == (dx, dy))?;
+
+        self.cells[i].as_ref()
+    }
+
+    /// Returns an iterator over the existing neighbours.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter().flatten()
+    }
+
+    /// Returns the number of existing neighbours matching `predicate`.
+    pub fn count(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.iter().filter(|cell| predicate(cell)).count()
+    }
+}
+
+impl<T> IntoIterator for Neighbourhood<T> {
+    type IntoIter = core::iter::Flatten<core::array::IntoIter<Option<T>, 8>>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.cells).flatten()
+    }
+}
+
+fn wrap(i: usize, delta: isize, len: usize) -> usize {
+    let delta = delta.rem_euclid(len as isize) as usize;
+
+    (i % len + delta) % len
+}
@@ -0,0 +1,543 @@
+//! Netpbm (PBM, PGM and PPM) import and export.
+//!
+//! | Pixel            | Format | Ascii | Binary |
+//! |------------------|--------|-------|--------|
+//! | `bool`           | PBM    | `P1`  | `P4`   |
+//! | `u8`, `u16`      | PGM    | `P2`  | `P5`   |
+//! | `[u8; 3]`        | PPM    | `P3`  | `P6`   |
+//!
+//! In PBM, `true` is black (`1`).
+//!
+//! As specified, binary headers end with exactly one whitespace byte after
+//! the last field (e.g. not `\r\n`, nor a comment): the raster starts right
+//! after it.
+
+use crate::*;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::{self, Read, Write},
+    string::String,
+    vec::Vec,
+};
+
+/// The raster encoding of a Netpbm image.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Encoding {
+    /// Plain text samples (`P1`, `P2`, `P3`).
+    Ascii,
+    /// Raw bytes (`P4`, `P5`, `P6`).
+    Binary,
+}
+
+/// Pixel types with a Netpbm format.
+pub trait Pixel: Copy {
+    /// The ascii and binary magic numbers.
+    const MAGIC: [&'static str; 2];
+    /// The number of samples per pixel.
+    const CHANNELS: usize;
+    /// The largest supported maxval (`1` for PBM, which has no maxval).
+    const MAXVAL: u16;
+
+    /// Creates a pixel from its samples.
+    fn from_samples(samples: &[u16]) -> Self;
+
+    /// Pushes the samples of this pixel.
+    fn samples(self, samples: &mut Vec<u16>);
+}
+
+impl Pixel for bool {
+    const CHANNELS: usize = 1;
+    const MAGIC: [&'static str; 2] = ["P1", "P4"];
+    const MAXVAL: u16 = 1;
+
+    fn from_samples(samples: &[u16]) -> Self {
+        samples[0] == 1
+    }
+
+    fn samples(self, samples: &mut Vec<u16>) {
+        samples.push(self as u16);
+    }
+}
+
+impl Pixel for u8 {
+    const CHANNELS: usize = 1;
+    const MAGIC: [&'static str; 2] = ["P2", "P5"];
+    const MAXVAL: u16 = u8::MAX as u16;
+
+    fn from_samples(samples: &[u16]) -> Self {
+        samples[0] as u8
+    }
+
+    fn samples(self, samples: &mut Vec<u16>) {
+        samples.push(self as u16);
+    }
+}
+
+impl Pixel for u16 {
+    const CHANNELS: usize = 1;
+    const MAGIC: [&'static str; 2] = ["P2", "P5"];
+    const MAXVAL: u16 = u16::MAX;
+
+    fn from_samples(samples: &[u16]) -> Self {
+        samples[0]
+    }
+
+    fn samples(self, samples: &mut Vec<u16>) {
+        samples.push(self);
+    }
+}
+
+impl Pixel for [u8; 3] {
+    const CHANNELS: usize = 3;
+    const MAGIC: [&'static str; 2] = ["P3", "P6"];
+    const MAXVAL: u16 = u8::MAX as u16;
+
+    fn from_samples(samples: &[u16]) -> Self {
+        [samples[0] as u8, samples[1] as u8, samples[2] as u8]
+    }
+
+    fn samples(self, samples: &mut Vec<u16>) {
+        samples.extend(self.iter().map(|&sample| sample as u16));
+    }
+}
+
+/// An error which can be returned when reading or writing Netpbm images.
+#[derive(Debug)]
+pub enum NetpbmError {
+    /// An I/O error.
+    Io(io::Error),
+    /// The magic number is unknown, or not a format of the pixel type.
+    Magic(String),
+    /// A header field is missing or malformed.
+    Header(&'static str),
+    /// The maxval is out of the pixel type's range.
+    Maxval(u32),
+    /// The image size differs from the expected size.
+    Size {
+        /// The expected size.
+        expected: Size,
+        /// The size of the image.
+        actual:   Size,
+    },
+    /// The raster is truncated or has invalid samples.
+    Raster,
+}
+
+impl Display for NetpbmError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::Magic(magic) => write!(f, "unexpected magic number {:?}", magic),
+            Self::Header(field) => write!(f, "missing or malformed {} in header", field),
+            Self::Maxval(maxval) => write!(f, "unsupported maxval {}", maxval),
+            Self::Size { expected, actual } => write!(
+                f,
+                "image is {}x{}, expected {}x{}",
+                actual.x, actual.y, expected.x, expected.y
+            ),
+            Self::Raster => f.write_str("truncated or invalid raster"),
+        }
+    }
+}
+
+impl Error for NetpbmError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NetpbmError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Reads a Netpbm image of `P` pixels (ascii or binary).
+pub fn read<P: Pixel>(mut reader: impl Read) -> Result<RowGrid1D<P, Vec<P>>, NetpbmError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let mut parser = Parser {
+        bytes: &bytes,
+        i:     0,
+    };
+    let magic = parser.magic()?;
+    let encoding = match P::MAGIC.iter().position(|m| *m == magic) {
+        Some(0) => Encoding::Ascii,
+        Some(_) => Encoding::Binary,
+        None => return Err(NetpbmError::Magic(magic)),
+    };
+
+    let x = parser.header_number("width")?;
+    let y = parser.header_number("height")?;
+    let maxval = if P::MAXVAL == 1 {
+        1
+    } else {
+        match parser.header_number("maxval")? {
+            maxval @ 1..=65535 if maxval <= P::MAXVAL as usize => maxval as u16,
+            maxval => return Err(NetpbmError::Maxval(maxval as u32)),
+        }
+    };
+
+    let size = Size { x, y };
+    let len = x
+        .checked_mul(y)
+        .and_then(|len| len.checked_mul(P::CHANNELS))
+        .ok_or(NetpbmError::Header("size"))?;
+    let samples = match encoding {
+        Encoding::Ascii => parser.ascii(len, maxval)?,
+        Encoding::Binary => {
+            // Exactly one whitespace before the raster, whose bytes may look
+            // like whitespace or comments
+            parser.i += 1;
+
+            if P::MAXVAL == 1 {
+                parser.bits(size)?
+            } else {
+                parser.binary(len, maxval)?
+            }
+        }
+    };
+
+    let items = samples.chunks(P::CHANNELS).map(P::from_samples).collect();
+    Ok(RowGrid1D::new_unchecked(size, items))
+}
+
+/// Reads a Netpbm image of `P` pixels, which must be of `size`.
+pub fn read_sized<P: Pixel>(
+    reader: impl Read,
+    size: Size,
+) -> Result<RowGrid1D<P, Vec<P>>, NetpbmError> {
+    let grid = read(reader)?;
+
+    if grid.size() == size {
+        Ok(grid)
+    } else {
+        Err(NetpbmError::Size {
+            expected: size,
+            actual:   grid.size(),
+        })
+    }
+}
+
+/// Writes `grid` as a Netpbm image, with the format of `P`.
+///
+/// PGM images of `u16` have a maxval of `65535`.
+pub fn write<P: Pixel, T: AsRef<[P]>>(
+    writer: impl Write,
+    grid: &RowGrid1D<P, T>,
+    encoding: Encoding,
+) -> Result<(), NetpbmError> {
+    write_image::<P, _>(writer, grid.size(), encoding, grid.as_ref().iter().copied())
+}
+
+/// Writes any `grid` as a PPM image, mapping items to RGB with `f`.
+pub fn write_rgb<G, F>(
+    writer: impl Write,
+    grid: G,
+    encoding: Encoding,
+    f: F,
+) -> Result<(), NetpbmError>
+where
+    G: GridRows,
+    F: FnMut(G::Item) -> [u8; 3],
+{
+    let size = grid.size();
+    let pixels = collect_rows(grid, f);
+
+    write_image::<[u8; 3], _>(writer, size, encoding, pixels)
+}
+
+fn write_image<P: Pixel, W: Write>(
+    writer: W,
+    size: Size,
+    encoding: Encoding,
+    pixels: impl IntoIterator<Item = P>,
+) -> Result<(), NetpbmError> {
+    let mut writer = io::BufWriter::new(writer);
+    let magic = P::MAGIC[(encoding == Encoding::Binary) as usize];
+
+    write!(writer, "{}\n{} {}\n", magic, size.x, size.y)?;
+    if P::MAXVAL != 1 {
+        writeln!(writer, "{}", P::MAXVAL)?;
+    }
+
+    let mut samples = Vec::with_capacity(size.x * P::CHANNELS);
+    let mut pixels = pixels.into_iter();
+
+    for _ in 0..size.y {
+        samples.clear();
+        pixels
+            .by_ref()
+            .take(size.x)
+            .for_each(|pixel| pixel.samples(&mut samples));
+
+        match encoding {
+            Encoding::Ascii => {
+                for (i, sample) in samples.iter().enumerate() {
+                    if i != 0 {
+                        writer.write_all(b" ")?;
+                    }
+                    write!(writer, "{}", sample)?;
+                }
+                writer.write_all(b"\n")?;
+            }
+            Encoding::Binary if P::MAXVAL == 1 =>
+                for byte in samples.chunks(8) {
+                    let bits = byte
+                        .iter()
+                        .enumerate()
+                        .fold(0u8, |bits, (i, bit)| bits | ((*bit as u8) << (7 - i)));
+
+                    writer.write_all(&[bits])?;
+                },
+            Encoding::Binary if P::MAXVAL > 255 =>
+                for sample in &samples {
+                    writer.write_all(&sample.to_be_bytes())?;
+                },
+            Encoding::Binary =>
+                for sample in &samples {
+                    writer.write_all(&[*sample as u8])?;
+                },
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// A Netpbm parser.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    i:     usize,
+}
+
+impl Parser<'_> {
+    fn magic(&mut self) -> Result<String, NetpbmError> {
+        let magic = self
+            .bytes
+            .get(..2)
+            .ok_or(NetpbmError::Header("magic number"))?;
+        self.i = 2;
+
+        Ok(String::from_utf8_lossy(magic).into_owned())
+    }
+
+    /// Skips whitespace and comments.
+    fn skip(&mut self) {
+        while let Some(byte) = self.bytes.get(self.i) {
+            match byte {
+                b'#' =>
+                    while self.bytes.get(self.i).is_some_and(|byte| *byte != b'\n') {
+                        self.i += 1;
+                    },
+                byte if byte.is_ascii_whitespace() => self.i += 1,
+                _ => break,
+            }
+        }
+    }
+
+    /// Parses a decimal number, after whitespace and comments.
+    fn number(&mut self) -> Option<usize> {
+        self.skip();
+
+        let start = self.i;
+        while self.bytes.get(self.i).is_some_and(u8::is_ascii_digit) {
+            self.i += 1;
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.i])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    fn header_number(&mut self, field: &'static str) -> Result<usize, NetpbmError> {
+        let number = self.number().ok_or(NetpbmError::Header(field))?;
+
+        // Followed by whitespace or a comment
+        match self.bytes.get(self.i) {
+            Some(byte) if byte.is_ascii_whitespace() || *byte == b'#' => Ok(number),
+            _ => Err(NetpbmError::Header(field)),
+        }
+    }
+
+    fn ascii(&mut self, len: usize, maxval: u16) -> Result<Vec<u16>, NetpbmError> {
+        (0..len)
+            .map(|_| {
+                // PBM samples need not be separated
+                let sample = if maxval == 1 {
+                    self.skip();
+                    let bit = self.bytes.get(self.i).and_then(|byte| match byte {
+                        b'0' => Some(0),
+                        b'1' => Some(1),
+                        _ => None,
+                    });
+                    self.i += 1;
+                    bit
+                } else {
+                    self.number()
+                };
+
+                match sample {
+                    Some(sample) if sample <= maxval as usize => Ok(sample as u16),
+                    _ => Err(NetpbmError::Raster),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the `len` raster bytes, or a raster error if truncated.
+    fn raster(&self, len: Option<usize>) -> Result<&[u8], NetpbmError> {
+        let end = len
+            .and_then(|len| self.i.checked_add(len))
+            .ok_or(NetpbmError::Raster)?;
+
+        self.bytes.get(self.i..end).ok_or(NetpbmError::Raster)
+    }
+
+    fn binary(&mut self, len: usize, maxval: u16) -> Result<Vec<u16>, NetpbmError> {
+        let width = if maxval > 255 { 2 } else { 1 };
+        let raster = self.raster(len.checked_mul(width))?;
+
+        raster
+            .chunks(width)
+            .map(|sample| match *sample {
+                [byte] => byte as u16,
+                [high, low] => u16::from_be_bytes([high, low]),
+                _ => unreachable!(),
+            })
+            .map(|sample| {
+                if sample <= maxval {
+                    Ok(sample)
+                } else {
+                    Err(NetpbmError::Raster)
+                }
+            })
+            .collect()
+    }
+
+    fn bits(&mut self, size: Size) -> Result<Vec<u16>, NetpbmError> {
+        let stride = size.x.div_ceil(8);
+        let raster = self.raster(stride.checked_mul(size.y))?;
+        let mut bits = Vec::with_capacity(size.x * size.y);
+
+        for row in raster.chunks(stride.max(1)).take(size.y) {
+            bits.extend((0..size.x).map(|x| ((row[x / 8] >> (7 - x % 8)) & 1) as u16));
+        }
+
+        Ok(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{format, string::String, vec, vec::Vec};
+
+    fn round_trip<P: Pixel + PartialEq + fmt::Debug>(grid: RowGrid1D<P, Vec<P>>) {
+        for &encoding in &[Encoding::Ascii, Encoding::Binary] {
+            let mut bytes = Vec::new();
+            write(&mut bytes, &grid, encoding).unwrap();
+
+            assert_eq!(read::<P>(&bytes[..]).unwrap(), grid);
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let size = Size { x: 11, y: 2 };
+        let bits = (0..22).map(|i| i % 3 == 0).collect();
+        round_trip(RowGrid1D::new(size, bits).unwrap());
+        round_trip(RowGrid1D::new(size, (0..22).map(|i| i * 11).collect::<Vec<u8>>()).unwrap());
+        round_trip(RowGrid1D::new(size, (0..22).map(|i| i * 2999).collect::<Vec<u16>>()).unwrap());
+        round_trip(RowGrid1D::new(size, (0..22).map(|i| [i, 2 * i, 3 * i]).collect()).unwrap());
+    }
+
+    #[test]
+    fn parse() {
+        let grid = super::read::<bool>(&b"P1\n# comment\n3 2\n010\n1 1 0"[..]).unwrap();
+        assert_eq!(grid.as_ref(), &[false, true, false, true, true, false]);
+
+        let grid = super::read::<u8>(&b"P5 2 1 100\n\x05\x64"[..]).unwrap();
+        assert_eq!(grid.as_ref(), &[5, 100]);
+
+        let bytes = b"P2 2 1 100 5 101";
+        assert!(matches!(
+            super::read::<u8>(&bytes[..]),
+            Err(NetpbmError::Raster)
+        ));
+        assert!(matches!(
+            super::read::<u16>(&b"P3 1 1 255 0 0 0"[..]),
+            Err(NetpbmError::Magic(_))
+        ));
+        assert!(matches!(
+            super::read::<u8>(&b"P2 1 1 1000 0"[..]),
+            Err(NetpbmError::Maxval(1000))
+        ));
+        assert!(matches!(
+            super::read::<u8>(&b"P2 1x 1 255 0"[..]),
+            Err(NetpbmError::Header("width"))
+        ));
+        assert!(matches!(
+            super::read::<u8>(&b"P5 2 1 255\n\x00"[..]),
+            Err(NetpbmError::Raster)
+        ));
+
+        // Only one whitespace before binary rasters
+        let grid = super::read::<u8>(&b"P5 2 1 255\n\n#"[..]).unwrap();
+        assert_eq!(grid.as_ref(), b"\n#");
+
+        assert!(matches!(
+            read_sized::<u8>(&b"P2 2 1 255 0 0"[..], Size { x: 1, y: 2 }),
+            Err(NetpbmError::Size {
+                expected: Size { x: 1, y: 2 },
+                actual:   Size { x: 2, y: 1 },
+            })
+        ));
+    }
+
+    #[test]
+    fn huge() {
+        // Sizes overflow
+        let huge = format!("P6 {} 1 255\n\x00\x00\x00", 1usize << 63);
+        assert!(matches!(
+            super::read::<[u8; 3]>(huge.as_bytes()),
+            Err(NetpbmError::Header("size"))
+        ));
+        let huge = format!("P2 {} {} 255 0", usize::MAX, 2);
+        assert!(matches!(
+            super::read::<u8>(huge.as_bytes()),
+            Err(NetpbmError::Header("size"))
+        ));
+
+        // Raster lengths overflow, or rasters are truncated
+        let huge = format!("P5 {} 1 65535\n\x00\x00", 1usize << 63);
+        assert!(matches!(
+            super::read::<u16>(huge.as_bytes()),
+            Err(NetpbmError::Raster)
+        ));
+        let huge = format!("P4 {} {}\n\x00", usize::MAX, 1);
+        assert!(matches!(
+            super::read::<bool>(huge.as_bytes()),
+            Err(NetpbmError::Raster)
+        ));
+    }
+
+    #[test]
+    fn rgb() {
+        let grid = ColGrid1D::new((2, 2).into(), vec![0u8, 1, 2, 3]).unwrap();
+        let mut bytes = Vec::new();
+
+        super::write_rgb(&mut bytes, &grid, Encoding::Ascii, |i| [*i, 0, 255]).unwrap();
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            "P3\n2 2\n255\n0 0 255 2 0 255\n1 0 255 3 0 255\n"
+        );
+    }
+}
@@ -0,0 +1,493 @@
+use crate::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{iter::Flatten, marker::PhantomData};
+
+pub type ColPlanarGrid<I, T, const N: usize> = PlanarGrid<ColMajor, I, T, N>;
+pub type RowPlanarGrid<I, T, const N: usize> = PlanarGrid<RowMajor, I, T, N>;
+
+/// A grid of `N` same-sized planes (structure of arrays), e.g. the channels
+/// of an image.
+///
+/// Each plane is laid out as a [`Grid1D`](Grid1D), see
+/// [`plane`](PlanarGrid::plane). As a grid, items are arrays of the `N`
+/// planes' items.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PlanarGrid<M, I, T, const N: usize> {
+    size:    M,
+    planes:  [T; N],
+    phantom: PhantomData<I>,
+}
+
+/// ### Constructors
+impl<M: Major, I, T, const N: usize> PlanarGrid<M, I, T, N> {
+    /// Creates a new [`PlanarGrid`](PlanarGrid), without checking sizes.
+    pub fn new_unchecked(size: Size, planes: [T; N]) -> Self {
+        Self {
+            size: size.into(),
+            planes,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new [`PlanarGrid`](PlanarGrid), or returns
+    /// [`None`](core::option::Option::None) if a plane's `len != x * y`.
+    pub fn new(size: Size, planes: [T; N]) -> Option<Self>
+    where
+        T: AsRef<[I]>,
+    {
+        let len = size.x.checked_mul(size.y)?;
+
+        if planes.iter().all(|plane| plane.as_ref().len() == len) {
+            Some(Self::new_unchecked(size, planes))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, M: Major, I, const N: usize> PlanarGrid<M, I, &'a [I], N> {
+    /// Creates a new [`PlanarGrid`](PlanarGrid) from a single `buffer` of
+    /// planes `plane_stride` apart, or returns
+    /// [`None`](core::option::Option::None) if planes overlap or are out of
+    /// bounds.
+    pub fn from_buffer(size: Size, buffer: &'a [I], plane_stride: usize) -> Option<Self> {
+        let len = plane_len(size, buffer.len(), plane_stride, N)?;
+
+        Some(Self::new_unchecked(
+            size,
+            core::array::from_fn(|n| &buffer[n * plane_stride..][..len]),
+        ))
+    }
+}
+
+impl<'a, M: Major, I, const N: usize> PlanarGrid<M, I, &'a mut [I], N> {
+    /// Creates a new [`PlanarGrid`](PlanarGrid) from a single mutable
+    /// `buffer` of planes `plane_stride` apart, or returns
+    /// [`None`](core::option::Option::None) if planes overlap or are out of
+    /// bounds.
+    pub fn from_buffer_mut(size: Size, buffer: &'a mut [I], plane_stride: usize) -> Option<Self> {
+        let len = plane_len(size, buffer.len(), plane_stride, N)?;
+        let mut rest = buffer;
+
+        Some(Self::new_unchecked(
+            size,
+            core::array::from_fn(|_| {
+                // The last plane may end before `plane_stride`
+                let mid = plane_stride.max(len).min(rest.len());
+                let (plane, tail) = core::mem::take(&mut rest).split_at_mut(mid);
+
+                rest = tail;
+                &mut plane[..len]
+            }),
+        ))
+    }
+}
+
+/// Returns the length of planes of `size`, if `n` planes `plane_stride`
+/// apart fit in `len` without overlapping.
+fn plane_len(size: Size, len: usize, plane_stride: usize, n: usize) -> Option<usize> {
+    let plane = size.x.checked_mul(size.y)?;
+    let end = match n {
+        0 => 0,
+        n => (n - 1).checked_mul(plane_stride)?.checked_add(plane)?,
+    };
+
+    if (n <= 1 || plane_stride >= plane) && end <= len {
+        Some(plane)
+    } else {
+        None
+    }
+}
+
+/// ### Planes
+impl<M: Major, I, T, const N: usize> PlanarGrid<M, I, T, N> {
+    /// Returns the `n`th plane, or [`None`](core::option::Option::None) if
+    /// `n >= N`.
+    pub fn plane(&self, n: usize) -> Option<Grid1D<M, I, &[I]>>
+    where
+        T: AsRef<[I]>,
+    {
+        let plane = self.planes.get(n)?;
+
+        Some(Grid1D::new_unchecked(self.size(), plane.as_ref()))
+    }
+
+    /// Returns the mutable `n`th plane, or
+    /// [`None`](core::option::Option::None) if `n >= N`.
+    pub fn plane_mut(&mut self, n: usize) -> Option<Grid1D<M, I, &mut [I]>>
+    where
+        T: AsMut<[I]>,
+    {
+        let size = self.size();
+        let plane = self.planes.get_mut(n)?;
+
+        Some(Grid1D::new_unchecked(size, plane.as_mut()))
+    }
+
+    /// Returns the planes.
+    pub fn planes(&self) -> [Grid1D<M, I, &[I]>; N]
+    where
+        T: AsRef<[I]>,
+    {
+        let size = self.size();
+
+        self.planes
+            .each_ref()
+            .map(|plane| Grid1D::new_unchecked(size, plane.as_ref()))
+    }
+
+    /// Returns the mutable planes.
+    pub fn planes_mut(&mut self) -> [Grid1D<M, I, &mut [I]>; N]
+    where
+        T: AsMut<[I]>,
+    {
+        let size = self.size();
+
+        self.planes
+            .each_mut()
+            .map(|plane| Grid1D::new_unchecked(size, plane.as_mut()))
+    }
+
+    /// Returns the underlying planes.
+    pub fn into_planes(self) -> [T; N] {
+        self.planes
+    }
+}
+
+/// ### Conversions
+#[cfg(feature = "alloc")]
+impl<M: Major, I: Clone, const N: usize> PlanarGrid<M, I, Vec<I>, N> {
+    /// Creates a new [`PlanarGrid`](PlanarGrid) from a grid of interleaved
+    /// items.
+    pub fn from_interleaved<T: AsRef<[[I; N]]>>(grid: &Grid1D<M, [I; N], T>) -> Self {
+        let items = grid.as_ref();
+
+        Self::new_unchecked(
+            grid.size(),
+            core::array::from_fn(|n| items.iter().map(|item| item[n].clone()).collect()),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: Major, I: Clone, T: AsRef<[I]>, const N: usize> PlanarGrid<M, I, T, N> {
+    /// Returns a grid of interleaved items.
+    pub fn to_interleaved(&self) -> Grid1D<M, [I; N], Vec<[I; N]>> {
+        let Size { x, y } = self.size();
+        let planes = self.planes.each_ref().map(AsRef::as_ref);
+        let items = (0..x * y)
+            .map(|i| planes.map(|plane| plane[i].clone()))
+            .collect();
+
+        Grid1D::new_unchecked(self.size(), items)
+    }
+}
+
+impl<M: Major, I, T, const N: usize> WithSize for PlanarGrid<M, I, T, N> {
+    fn size(&self) -> Size {
+        self.size.into()
+    }
+}
+
+impl<'a, M: Major, I, T: AsRef<[I]>, const N: usize> Grid for &'a PlanarGrid<M, I, T, N> {
+    type Item = [&'a I; N];
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let index = self.size.index(index.unchecked());
+
+        self.planes.each_ref().map(|plane| {
+            debug_assert!(index < plane.as_ref().len(), "Index out of bounds");
+            plane.as_ref().get_unchecked(index)
+        })
+    }
+}
+
+impl<'a, M: Major, I, T: AsMut<[I]>, const N: usize> Grid for &'a mut PlanarGrid<M, I, T, N> {
+    type Item = [&'a mut I; N];
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let index = self.size.index(index.unchecked());
+
+        self.planes.each_mut().map(|plane| {
+            debug_assert!(index < plane.as_mut().len(), "Index out of bounds");
+            plane.as_mut().get_unchecked_mut(index)
+        })
+    }
+}
+
+macro_rules! grid1d {
+    ($($Trait:ident $Assoc:ident $fn:ident $row:literal)*) => { $(
+        impl<'a, M: Major, I, T: AsRef<[I]>, const N: usize> $Trait
+            for &'a PlanarGrid<M, I, T, N>
+        {
+            type $Assoc = Cells<'a, I, N>;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let (i, range) = index.$fn(self.size());
+                let (point, step) = if $row {
+                    (Point { x: range.start, y: i }, self.steps().x)
+                } else {
+                    (Point { x: i, y: range.start }, self.steps().y)
+                };
+
+                Cells {
+                    planes: self.planes.each_ref().map(AsRef::as_ref),
+                    index: self.size.index(point),
+                    step,
+                    len: range.end - range.start,
+                }
+            }
+        }
+    )* };
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident $rows:literal)*) => { $(
+        impl<'a, M: Major, I, T: AsRef<[I]>, const N: usize> $Trait
+            for &'a PlanarGrid<M, I, T, N>
+        {
+            type $Assoc = CellLines<'a, I, N>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                self.lines(index.unchecked(self.size()), $rows)
+            }
+        }
+    )* };
+}
+
+grid1d!(
+    GridRow Row row_unchecked true
+    GridCol Col col_unchecked false
+);
+
+grid2d!(
+    GridRows Rows rows_unchecked true
+    GridCols Cols cols_unchecked false
+);
+
+impl<'a, M: Major, I, T: AsRef<[I]>, const N: usize> GridItems for &'a PlanarGrid<M, I, T, N> {
+    type Items = Flatten<CellLines<'a, I, N>>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.lines(index.unchecked(self.size()), is_row_major::<M>())
+            .flatten()
+    }
+}
+
+impl<M: Major, I, T: AsRef<[I]>, const N: usize> PlanarGrid<M, I, T, N> {
+    /// Returns the distances between items along x and y.
+    fn steps(&self) -> Point {
+        M::new(1, self.size.major()).into()
+    }
+
+    /// Returns the rows (or columns) of `rect`.
+    fn lines(&self, Rect { x, y }: Rect, rows: bool) -> CellLines<'_, I, N> {
+        let steps = self.steps();
+        let start = self.size.index(Point {
+            x: x.start,
+            y: y.start,
+        });
+        let (x, y) = (x.end - x.start, y.end - y.start);
+        let planes = self.planes.each_ref().map(AsRef::as_ref);
+
+        if rows {
+            CellLines {
+                planes,
+                start,
+                stride: steps.y,
+                count: y,
+                step: steps.x,
+                len: x,
+            }
+        } else {
+            CellLines {
+                planes,
+                start,
+                stride: steps.x,
+                count: x,
+                step: steps.y,
+                len: y,
+            }
+        }
+    }
+}
+
+/// Iterator over the cells of a row or column of a
+/// [`PlanarGrid`](PlanarGrid).
+#[derive(Clone, Debug)]
+pub struct Cells<'a, I, const N: usize> {
+    planes: [&'a [I]; N],
+    index:  usize,
+    step:   usize,
+    len:    usize,
+}
+
+impl<'a, I, const N: usize> Iterator for Cells<'a, I, N> {
+    type Item = [&'a I; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            let index = self.index;
+
+            self.index += self.step;
+            self.len -= 1;
+
+            Some(self.planes.map(|plane| &plane[index]))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, I, const N: usize> ExactSizeIterator for Cells<'a, I, N> {}
+
+/// Iterator over the rows or columns of a [`PlanarGrid`](PlanarGrid).
+#[derive(Clone, Debug)]
+pub struct CellLines<'a, I, const N: usize> {
+    planes: [&'a [I]; N],
+    start:  usize,
+    stride: usize,
+    count:  usize,
+    step:   usize,
+    len:    usize,
+}
+
+impl<'a, I, const N: usize> Iterator for CellLines<'a, I, N> {
+    type Item = Cells<'a, I, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            None
+        } else {
+            let cells = Cells {
+                planes: self.planes,
+                index:  self.start,
+                step:   self.step,
+                len:    self.len,
+            };
+
+            self.start += self.stride;
+            self.count -= 1;
+
+            Some(cells)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+}
+
+impl<'a, I, const N: usize> ExactSizeIterator for CellLines<'a, I, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn planar() {
+        let rgb = RowGrid1D::new((2, 2).into(), vec![[1, 2, 3], [4, 5, 6], [7, 8, 9], [
+            10, 11, 12,
+        ]])
+        .unwrap();
+        let mut planar = RowPlanarGrid::from_interleaved(&rgb);
+
+        assert_eq!(planar.plane(1).unwrap().as_ref(), &[2, 5, 8, 11]);
+        assert!(planar.plane(3).is_none());
+        assert_eq!((&planar).item((1, 0)), Some([&4, &5, &6]));
+        assert_eq!(
+            (&planar)
+                .col((1, ..))
+                .unwrap()
+                .map(|[r, _, _]| *r)
+                .collect::<Vec<_>>(),
+            [4, 10]
+        );
+        assert_eq!(
+            (&planar)
+                .cropped((1.., ..))
+                .unwrap()
+                .items(..)
+                .unwrap()
+                .map(|[_, _, b]| *b)
+                .collect::<Vec<_>>(),
+            [6, 12]
+        );
+        assert_eq!(planar.to_interleaved(), rgb);
+
+        let [_, green, _] = (&mut planar).item((0, 1)).unwrap();
+        *green = 0;
+        planar.planes_mut()[2].as_mut().fill(0);
+        assert_eq!(planar.into_planes(), [
+            vec![1, 4, 7, 10],
+            vec![2, 5, 0, 11],
+            vec![0, 0, 0, 0]
+        ]);
+    }
+
+    #[test]
+    fn buffer() {
+        let buffer = (0..10).collect::<Vec<_>>();
+        let planar = ColPlanarGrid::<_, _, 2>::from_buffer((2, 2).into(), &buffer, 5).unwrap();
+
+        assert_eq!(
+            (&planar)
+                .rows(..)
+                .unwrap()
+                .map(|row| row.map(|[a, b]| a + b).collect())
+                .collect::<Vec<Vec<_>>>(),
+            [[5, 9], [7, 11]]
+        );
+        assert!(ColPlanarGrid::<_, _, 2>::from_buffer((2, 2).into(), &buffer, 3).is_none());
+        assert!(ColPlanarGrid::<_, _, 3>::from_buffer((2, 2).into(), &buffer, 4).is_none());
+        assert!(RowPlanarGrid::<_, _, 2>::new((2, 2).into(), [vec![0; 4], vec![0; 3]]).is_none());
+
+        let mut buffer = vec![0; 8];
+        let mut planar =
+            RowPlanarGrid::<_, _, 2>::from_buffer_mut((2, 2).into(), &mut buffer, 4).unwrap();
+        *(&mut planar).item((1, 1)).unwrap()[1] = 1;
+        assert_eq!(buffer, [0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn buffer_mut() {
+        fn planes<const N: usize>() {
+            for size in [(2, 2), (3, 1), (0, 2)] {
+                for plane_stride in 0..6 {
+                    for len in 0..12 {
+                        let buffer = (0..len).collect::<Vec<_>>();
+                        let mut buffer_mut = buffer.clone();
+
+                        let planar = RowPlanarGrid::<_, _, N>::from_buffer(
+                            size.into(),
+                            &buffer,
+                            plane_stride,
+                        )
+                        .map(|planar| planar.into_planes().map(<[_]>::to_vec));
+                        let planar_mut = RowPlanarGrid::<_, _, N>::from_buffer_mut(
+                            size.into(),
+                            &mut buffer_mut,
+                            plane_stride,
+                        )
+                        .map(|planar| planar.into_planes().map(|plane| plane.to_vec()));
+
+                        assert_eq!(planar, planar_mut, "{:?} {} {}", size, plane_stride, len);
+                    }
+                }
+            }
+        }
+
+        planes::<0>();
+        planes::<1>();
+        planes::<2>();
+        planes::<3>();
+    }
+}
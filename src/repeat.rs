@@ -1,6 +1,6 @@
 use crate::*;
-use std::{
-    iter::{repeat as std_repeat, Repeat as StdRepeat, Take},
+use core::{
+    iter::{repeat_n, RepeatN},
     ops::Range,
 };
 
@@ -13,6 +13,7 @@ pub fn repeat<I: Clone>(size: Size, item: I) -> Repeat<I> {
 ///
 /// This `struct` is created by [`repeat`](repeat::repeat()).
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Repeat<I> {
     size: Size,
     item: I,
@@ -35,12 +36,12 @@ impl<I: Clone> Grid for Repeat<I> {
 macro_rules! grid1d {
     ($($Trait:ident $Assoc:ident $fn:ident)*) => { $(
         impl<I: Clone> $Trait for Repeat<I> {
-            type $Assoc = Take<StdRepeat<I>>;
+            type $Assoc = RepeatN<I>;
 
             unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
                 let (_, Range { start, end }) = index.$fn(self.size);
 
-                std_repeat(self.item).take(end - start)
+                repeat_n(self.item, end - start)
             }
         }
     )* };
@@ -53,12 +54,12 @@ macro_rules! grid2d {
         $main:ident $cross:ident
     )*) => { $(
         impl<I: Clone> $Trait for Repeat<I> {
-            type $Assoc = Take<StdRepeat<Self::$Item>>;
+            type $Assoc = RepeatN<Self::$Item>;
 
             unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
                 let Point { $x, $y } = index.unchecked(self.size);
 
-                std_repeat(self.$item((0, $main))).take($cross.end - $cross.start)
+                repeat_n(self.$item((0, $main)), $cross.end - $cross.start)
             }
         }
     )* };
@@ -75,11 +76,11 @@ grid2d!(x y
 );
 
 impl<I: Clone> GridItems for Repeat<I> {
-    type Items = Take<StdRepeat<Self::Item>>;
+    type Items = RepeatN<Self::Item>;
 
     unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
         let Point { x, y } = index.unchecked(self.size);
 
-        std_repeat(self.item).take((x.end - x.start) * (y.end - y.start))
+        repeat_n(self.item, (x.end - x.start) * (y.end - y.start))
     }
 }
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::Range;
+use core::ops::Range;
 
 pub struct Items<F> {
     fun:    F,
@@ -38,18 +38,17 @@ impl<I, F: FnMut(Point) -> I> Iterator for Items<F> {
     type Item = I;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.yend || self.xstart >= self.xend {
+            return None;
+        }
+
         let (x, y) = (self.x, self.y);
 
-        let (x, y) = if x < self.xend {
-            self.x += 1;
-            (x, y)
-        } else if y < self.yend {
+        self.x += 1;
+        if self.x == self.xend {
             self.x = self.xstart;
             self.y += 1;
-            (x, y)
-        } else {
-            return None;
-        };
+        }
 
         Some((self.fun)(Point { x, y }))
     }
@@ -1,5 +1,5 @@
 use crate::*;
-use std::{marker::PhantomData, ops::Range};
+use core::{marker::PhantomData, ops::Range};
 
 pub struct Iter1D<M, F> {
     fun:      F,
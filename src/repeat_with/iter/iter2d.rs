@@ -1,5 +1,5 @@
 use crate::*;
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 pub struct Iter2D<M, F> {
     fun:      F,
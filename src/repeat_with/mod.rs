@@ -71,3 +71,20 @@ impl<I, F: FnMut(Point) -> I> GridItems for RepeatWith<F> {
         Self::Items::new(self.fun, index.unchecked(self.size))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn items() {
+        let grid = repeat_with((3, 3).into(), |Point { x, y }| (x, y));
+
+        assert!(grid
+            .items((1.., 1..))
+            .unwrap()
+            .eq([(1, 1), (2, 1), (1, 2), (2, 2)]));
+        assert_eq!(grid.items((1..1, ..)).unwrap().next(), None);
+    }
+}
@@ -0,0 +1,592 @@
+use crate::*;
+use core::{iter::Flatten, marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
+
+pub type ColStridedGrid<I, T> = StridedGrid<ColMajor, I, T>;
+pub type RowStridedGrid<I, T> = StridedGrid<RowMajor, I, T>;
+
+/// A grid over a slice with explicit `offset` and strides.
+///
+/// The item at `point` is at `offset + major * major_stride + minor *
+/// minor_stride`, where `major` and `minor` are `point`'s coordinates on the
+/// major and minor axis of `M`.
+///
+/// This describes sub-rectangles of larger buffers, padded buffers (where
+/// `minor_stride` is greater than the major length) or interleaved channels
+/// (where `major_stride` is greater than `1`).
+///
+/// Lines with a stride of `1` (e.g. rows of a cropped
+/// [`RowGrid1D`](RowGrid1D)) are slices, see [`Strided::as_slice`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct StridedGrid<M, I, T> {
+    size:    M,
+    offset:  usize,
+    strides: M,
+    items:   T,
+    phantom: PhantomData<I>,
+}
+
+/// ### Constructors
+impl<M: Major, I, T> StridedGrid<M, I, T> {
+    /// Creates a new [`StridedGrid`](StridedGrid), without checking bounds
+    /// and overlaps.
+    ///
+    /// # Safety
+    ///
+    /// All items must be in bounds of `items`, and distinct items must be at
+    /// distinct indices, as checked by [`new`](StridedGrid::new).
+    pub unsafe fn new_unchecked(
+        size: Size,
+        offset: usize,
+        major_stride: usize,
+        minor_stride: usize,
+        items: T,
+    ) -> Self {
+        Self {
+            size: size.into(),
+            offset,
+            strides: M::new(major_stride, minor_stride),
+            items,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new [`StridedGrid`](StridedGrid), or returns
+    /// [`None`](core::option::Option::None) if items are out of bounds of
+    /// `items` or overlap.
+    ///
+    /// Items do not overlap when strides are non-zero and majors do not
+    /// interleave (`minor_stride` is greater than the extent of a major) or
+    /// minors do not interleave (`major_stride` is greater than the extent of
+    /// a minor).
+    pub fn new(
+        size: Size,
+        offset: usize,
+        major_stride: usize,
+        minor_stride: usize,
+        items: T,
+    ) -> Option<Self>
+    where
+        T: AsRef<[I]>,
+    {
+        let msize = M::from(size);
+        let (major, minor) = (msize.major(), msize.minor());
+
+        if major != 0 && minor != 0 {
+            let major_extent = (major - 1).checked_mul(major_stride)?;
+            let minor_extent = (minor - 1).checked_mul(minor_stride)?;
+            let last = offset
+                .checked_add(major_extent)?
+                .checked_add(minor_extent)?;
+
+            if last >= items.as_ref().len() {
+                return None;
+            }
+
+            let majors_apart =
+                (major == 1 || major_stride != 0) && (minor == 1 || minor_stride > major_extent);
+            let minors_apart =
+                (minor == 1 || minor_stride != 0) && (major == 1 || major_stride > minor_extent);
+            if !majors_apart && !minors_apart {
+                return None;
+            }
+        }
+
+        // SAFETY: bounds and overlaps are checked
+        Some(unsafe { Self::new_unchecked(size, offset, major_stride, minor_stride, items) })
+    }
+}
+
+/// ### Strides
+impl<M: Major, I, T> StridedGrid<M, I, T> {
+    /// Returns the index of the first item.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the distance between items along the major axis.
+    pub fn major_stride(&self) -> usize {
+        self.strides.major()
+    }
+
+    /// Returns the distance between items along the minor axis.
+    pub fn minor_stride(&self) -> usize {
+        self.strides.minor()
+    }
+
+    fn index(&self, Point { x, y }: Point) -> usize {
+        let strides: Point = self.strides.into();
+
+        self.offset + x * strides.x + y * strides.y
+    }
+
+    /// Returns the layout of the row (or column) `index`.
+    fn line(&self, (i, range): (usize, Range<usize>), row: bool) -> LinesLayout {
+        let rect = if row {
+            Rect {
+                x: range,
+                y: i..i + 1,
+            }
+        } else {
+            Rect {
+                x: i..i + 1,
+                y: range,
+            }
+        };
+
+        self.lines(rect, row)
+    }
+
+    /// Returns the layout of the rows (or columns) of `rect`.
+    fn lines(&self, Rect { x, y }: Rect, rows: bool) -> LinesLayout {
+        let strides: Point = self.strides.into();
+        let start = self.index(Point {
+            x: x.start,
+            y: y.start,
+        });
+        let (x, y) = (x.end - x.start, y.end - y.start);
+
+        if rows {
+            LinesLayout {
+                start,
+                stride: strides.y,
+                count: y,
+                step: strides.x,
+                len: x,
+            }
+        } else {
+            LinesLayout {
+                start,
+                stride: strides.x,
+                count: x,
+                step: strides.y,
+                len: y,
+            }
+        }
+    }
+}
+
+impl<M: Major, I, T> WithSize for StridedGrid<M, I, T> {
+    fn size(&self) -> Size {
+        self.size.into()
+    }
+}
+
+impl<M, I, T: AsRef<[I]>> AsRef<[I]> for StridedGrid<M, I, T> {
+    fn as_ref(&self) -> &[I] {
+        self.items.as_ref()
+    }
+}
+
+impl<M, I, T: AsMut<[I]>> AsMut<[I]> for StridedGrid<M, I, T> {
+    fn as_mut(&mut self) -> &mut [I] {
+        self.items.as_mut()
+    }
+}
+
+macro_rules! from {
+    ($($As:ident $as:ident $(($mut:ident))?)*) => { $(
+        impl<'a, M: Major, I, T: $As<[I]>> From<&'a $($mut)? Grid1D<M, I, T>>
+            for StridedGrid<M, I, &'a $($mut)? [I]>
+        {
+            fn from(grid: &'a $($mut)? Grid1D<M, I, T>) -> Self {
+                let (size, major) = (grid.size(), grid.msize().major());
+
+                // SAFETY: Grid1D is contiguous
+                unsafe { Self::new_unchecked(size, 0, 1, major, grid.$as()) }
+            }
+        }
+
+        impl<'a, M: Major, I, T: $As<[I]>> From<Cropped<&'a $($mut)? Grid1D<M, I, T>>>
+            for StridedGrid<M, I, &'a $($mut)? [I]>
+        {
+            /// Keeps lines contiguous, as slices.
+            fn from(cropped: Cropped<&'a $($mut)? Grid1D<M, I, T>>) -> Self {
+                let size = cropped.size();
+                let (rect, grid) = cropped.into_parts();
+                let msize = grid.msize();
+                let start = Point {
+                    x: rect.x.start,
+                    y: rect.y.start,
+                };
+
+                // SAFETY: crops of Grid1Ds are in bounds and contiguous
+                unsafe {
+                    Self::new_unchecked(
+                        size,
+                        msize.index(start),
+                        1,
+                        msize.major(),
+                        grid.$as(),
+                    )
+                }
+            }
+        }
+    )* };
+}
+
+from!(
+    AsRef as_ref
+    AsMut as_mut (mut)
+);
+
+impl<'a, M: Major, I, T: AsRef<[I]>> Grid for &'a StridedGrid<M, I, T> {
+    type Item = &'a I;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let index = self.index(index.unchecked());
+        debug_assert!(index < self.items.as_ref().len(), "Index out of bounds");
+
+        self.items.as_ref().get_unchecked(index)
+    }
+}
+
+impl<'a, M: Major, I, T: AsMut<[I]>> Grid for &'a mut StridedGrid<M, I, T> {
+    type Item = &'a mut I;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let index = self.index(index.unchecked());
+        debug_assert!(index < self.items.as_mut().len(), "Index out of bounds");
+
+        self.items.as_mut().get_unchecked_mut(index)
+    }
+}
+
+macro_rules! grid {
+    ($($As:ident $as:ident $ptr:ident $Line:ident $Lines:ident $(($mut:ident))?)*) => { $(
+        grid!(impl [LINE] $As $as $ptr $Line GridRow Row row_unchecked true $(($mut))?);
+        grid!(impl [LINE] $As $as $ptr $Line GridCol Col col_unchecked false $(($mut))?);
+        grid!(impl [LINES] $As $as $ptr $Lines GridRows Rows rows_unchecked true $(($mut))?);
+        grid!(impl [LINES] $As $as $ptr $Lines GridCols Cols cols_unchecked false $(($mut))?);
+
+        impl<'a, M: Major, I, T: $As<[I]>> GridItems for &'a $($mut)? StridedGrid<M, I, T> {
+            type Items = Flatten<$Lines<'a, I>>;
+
+            unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+                let layout = self.lines(index.unchecked(self.size()), is_row_major::<M>());
+
+                $Lines::new(self.items.$as().$ptr(), layout).flatten()
+            }
+        }
+    )* };
+    (impl [LINE]
+        $As:ident $as:ident $ptr:ident $Line:ident
+        $Trait:ident $Assoc:ident $fn:ident $row:literal
+        $(($mut:ident))?
+    ) => {
+        impl<'a, M: Major, I, T: $As<[I]>> $Trait for &'a $($mut)? StridedGrid<M, I, T> {
+            type $Assoc = $Line<'a, I>;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let layout = self.line(index.$fn(self.size()), $row);
+
+                $Line::new(self.items.$as().$ptr(), layout.start, layout.step, layout.len)
+            }
+        }
+    };
+    (impl [LINES]
+        $As:ident $as:ident $ptr:ident $Lines:ident
+        $Trait:ident $Assoc:ident $fn:ident $rows:literal
+        $(($mut:ident))?
+    ) => {
+        impl<'a, M: Major, I, T: $As<[I]>> $Trait for &'a $($mut)? StridedGrid<M, I, T> {
+            type $Assoc = $Lines<'a, I>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let layout = self.lines(index.unchecked(self.size()), $rows);
+
+                $Lines::new(self.items.$as().$ptr(), layout)
+            }
+        }
+    };
+}
+
+grid!(
+    AsRef as_ref as_ptr Strided StridedLines
+    AsMut as_mut as_mut_ptr StridedMut StridedLinesMut (mut)
+);
+
+/// Layout of lines: `count` lines `stride` apart from `start`, of `len`
+/// items `step` apart.
+#[derive(Copy, Clone, Debug)]
+struct LinesLayout {
+    start:  usize,
+    stride: usize,
+    count:  usize,
+    step:   usize,
+    len:    usize,
+}
+
+macro_rules! iter {
+    ($(
+        $(#[$meta:meta])*
+        $Iter:ident [$Ptr:ident $($mut:ident)?];
+        $(#[$lines_meta:meta])*
+        $Lines:ident
+    )*) => { $(
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $Iter<'a, I> {
+            ptr:      *$Ptr I,
+            step:     usize,
+            len:      usize,
+            _phantom: PhantomData<&'a $($mut)? I>,
+        }
+
+        // SAFETY: iterators behave as their references
+        unsafe impl<'a, I: Sync> Sync for $Iter<'a, I> {}
+
+        impl<'a, I> $Iter<'a, I> {
+            /// # Safety
+            ///
+            /// The `len` items `step` apart from `ptr + start` must be valid
+            /// and distinct (for `'a`).
+            unsafe fn new(ptr: *$Ptr I, start: usize, step: usize, len: usize) -> Self {
+                Self {
+                    ptr: ptr.wrapping_add(start),
+                    step,
+                    len,
+                    _phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, I> Iterator for $Iter<'a, I> {
+            type Item = &'a $($mut)? I;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.len == 0 {
+                    None
+                } else {
+                    // SAFETY: users guaranty items are valid at construction
+                    let item = unsafe { &$($mut)? *self.ptr };
+
+                    self.len -= 1;
+                    self.ptr = self.ptr.wrapping_add(self.step);
+
+                    Some(item)
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<'a, I> ExactSizeIterator for $Iter<'a, I> {}
+
+        $(#[$lines_meta])*
+        #[derive(Debug)]
+        pub struct $Lines<'a, I> {
+            ptr:      *$Ptr I,
+            layout:   LinesLayout,
+            _phantom: PhantomData<&'a $($mut)? I>,
+        }
+
+        // SAFETY: iterators behave as their references
+        unsafe impl<'a, I: Sync> Sync for $Lines<'a, I> {}
+
+        impl<'a, I> $Lines<'a, I> {
+            /// # Safety
+            ///
+            /// All items of `layout` from `ptr` must be valid and distinct
+            /// (for `'a`).
+            unsafe fn new(ptr: *$Ptr I, layout: LinesLayout) -> Self {
+                Self {
+                    ptr,
+                    layout,
+                    _phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, I> Iterator for $Lines<'a, I> {
+            type Item = $Iter<'a, I>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let LinesLayout {
+                    start,
+                    stride,
+                    count,
+                    step,
+                    len,
+                } = &mut self.layout;
+
+                if *count == 0 {
+                    None
+                } else {
+                    // SAFETY: users guaranty items are valid at construction
+                    let line = unsafe { $Iter::new(self.ptr, *start, *step, *len) };
+
+                    *count -= 1;
+                    *start += *stride;
+
+                    Some(line)
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.layout.count, Some(self.layout.count))
+            }
+        }
+
+        impl<'a, I> ExactSizeIterator for $Lines<'a, I> {}
+    )* };
+}
+
+iter!(
+    /// Iterator over a line of a [`StridedGrid`](StridedGrid).
+    Strided [const];
+    /// Iterator over the rows or columns of a [`StridedGrid`](StridedGrid).
+    StridedLines
+    /// Mutable iterator over a line of a [`StridedGrid`](StridedGrid).
+    StridedMut [mut mut];
+    /// Mutable iterator over the rows or columns of a
+    /// [`StridedGrid`](StridedGrid).
+    StridedLinesMut
+);
+
+// SAFETY: iterators behave as their references
+unsafe impl<'a, I: Sync> Send for Strided<'a, I> {}
+unsafe impl<'a, I: Sync> Send for StridedLines<'a, I> {}
+unsafe impl<'a, I: Send> Send for StridedMut<'a, I> {}
+unsafe impl<'a, I: Send> Send for StridedLinesMut<'a, I> {}
+
+impl<'a, I> Clone for Strided<'a, I> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, I> Clone for StridedLines<'a, I> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, I> Strided<'a, I> {
+    /// Returns the remaining items as a slice if they are contiguous, or
+    /// [`None`](core::option::Option::None).
+    pub fn as_slice(&self) -> Option<&'a [I]> {
+        if self.step == 1 || self.len <= 1 {
+            // SAFETY: items are valid, contiguous and borrowed for 'a
+            Some(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, I> StridedMut<'a, I> {
+    /// Returns the remaining items as a slice if they are contiguous, or
+    /// gives back `self`.
+    pub fn into_slice(self) -> Result<&'a mut [I], Self> {
+        if self.step == 1 || self.len <= 1 {
+            // SAFETY: items are valid, contiguous and borrowed for 'a
+            Ok(unsafe { from_raw_parts_mut(self.ptr, self.len) })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn strided() {
+        // 3x2 grid in a buffer with a pitch of 4, from index 1
+        let items = (0..9).collect::<Vec<_>>();
+        let grid = RowStridedGrid::new((3, 2).into(), 1, 1, 4, &items[..]).unwrap();
+
+        assert_eq!(grid.size(), Size { x: 3, y: 2 });
+        assert_eq!((&grid).item((2, 1)), Some(&7));
+        assert_eq!((&grid).item((3, 1)), None);
+        assert_eq!(
+            (&grid)
+                .rows(..)
+                .unwrap()
+                .map(Iterator::sum)
+                .collect::<Vec<i32>>(),
+            [6, 18]
+        );
+        assert_eq!(
+            (&grid).col((1, ..)).unwrap().copied().collect::<Vec<_>>(),
+            [2, 6]
+        );
+        assert_eq!(
+            (&grid)
+                .items((1.., ..))
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>(),
+            [2, 3, 6, 7]
+        );
+
+        // Green channel of 2x2 RGB pixels, transposed
+        let rgb = (0..12).collect::<Vec<_>>();
+        let green = ColStridedGrid::new((2, 2).into(), 1, 3, 6, &rgb).unwrap();
+        assert_eq!((&green).items(..).unwrap().copied().collect::<Vec<_>>(), [
+            1, 4, 7, 10
+        ]);
+        assert_eq!((&green).row((0, ..)).unwrap().as_slice(), None);
+
+        // Out of bounds or overlapping
+        assert!(RowStridedGrid::new((3, 2).into(), 3, 1, 4, &items).is_none());
+        assert!(RowStridedGrid::new((3, 2).into(), 0, 1, 2, &items).is_none());
+        assert!(RowStridedGrid::new((3, 2).into(), 0, 2, 2, &items).is_none());
+        assert!(RowStridedGrid::new((3, 2).into(), 0, 2, 1, &items).is_some());
+        assert!(RowStridedGrid::new((2, 2).into(), 0, 2, 4, &items).is_some());
+        assert!(RowStridedGrid::new((3, 1).into(), 0, 0, 5, &items).is_none());
+        assert!(RowStridedGrid::new((0, 5).into(), 100, 0, 0, &items).is_some());
+    }
+
+    #[test]
+    fn mutable() {
+        let mut items = vec![0; 8];
+        let mut grid = RowStridedGrid::new((2, 2).into(), 0, 2, 4, &mut items[..]).unwrap();
+
+        let mut rows = (&mut grid).rows(..).unwrap();
+        let (first, second) = (rows.next().unwrap(), rows.next().unwrap());
+        first.zip(second).for_each(|(a, b)| {
+            *a = 1;
+            *b = 2;
+        });
+        (&mut grid)
+            .col((1, ..))
+            .unwrap()
+            .for_each(|item| *item += 10);
+        assert_eq!(items, [1, 0, 11, 0, 2, 0, 12, 0]);
+    }
+
+    #[test]
+    fn cropped() {
+        let mut grid = RowGrid1D::new((4, 3).into(), (0..12).collect::<Vec<_>>()).unwrap();
+
+        let strided = StridedGrid::from((&grid).cropped((1..3, 1..)).unwrap());
+        assert_eq!(
+            (&strided)
+                .rows(..)
+                .unwrap()
+                .map(|row| row.as_slice().unwrap())
+                .collect::<Vec<_>>(),
+            [&[5, 6], &[9, 10]]
+        );
+        assert!((&strided).col((0, ..)).unwrap().as_slice().is_none());
+
+        let mut strided = StridedGrid::from((&mut grid).cropped((2.., ..2)).unwrap());
+        for row in (&mut strided).rows(..).unwrap() {
+            row.into_slice().unwrap().reverse();
+        }
+        assert_eq!(&grid.as_ref()[..8], &[0, 1, 3, 2, 4, 5, 7, 6]);
+
+        let cols = ColGrid1D::new((3, 2).into(), (0..6).collect::<Vec<_>>()).unwrap();
+        let strided = StridedGrid::from((&cols).cropped((1.., 1..)).unwrap());
+        assert_eq!(
+            (&strided).items(..).unwrap().copied().collect::<Vec<_>>(),
+            [3, 5]
+        );
+    }
+}
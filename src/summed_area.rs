@@ -0,0 +1,201 @@
+use crate::*;
+use core::ops::{Add, Sub};
+use std::{vec, vec::Vec};
+
+/// [`Numeric`](Numeric) values that can be summed in a
+/// [`SummedArea`](SummedArea).
+pub trait Summable: Numeric {
+    /// The type of sums, wide enough not to overflow in practice.
+    type Sum: Numeric + Default + Add<Output = Self::Sum> + Sub<Output = Self::Sum>;
+
+    /// Converts to [`Sum`](Summable::Sum).
+    fn widen(self) -> Self::Sum;
+}
+
+macro_rules! summable {
+    ($($T:ident => $Sum:ident)*) => { $(
+        impl Summable for $T {
+            type Sum = $Sum;
+
+            fn widen(self) -> $Sum {
+                self as $Sum
+            }
+        }
+    )* };
+}
+
+summable!(
+    u8 => u64 u16 => u64 u32 => u64 u64 => u128 usize => u128 u128 => u128
+    i8 => i64 i16 => i64 i32 => i64 i64 => i128 isize => i128 i128 => i128
+    f32 => f64 f64 => f64
+);
+
+/// A summed-area table (integral image), for constant time region sums.
+///
+/// The table has an extra leading row and column of zeros:
+/// `sums[(x + 1, y + 1)]` is the sum of the items in `(..=x, ..=y)`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SummedArea<T: Summable> {
+    size: Size,
+    sums: Vec<T::Sum>,
+}
+
+impl<T: Summable> SummedArea<T> {
+    /// Builds the summed-area table of `grid`.
+    pub fn new<G>(grid: G) -> Self
+    where
+        G: GridRows,
+        G::Item: Sample<Value = T>,
+    {
+        let size = grid.size();
+        let values = collect_rows(grid, |item| item.value().widen());
+        let mut table = Self {
+            size,
+            sums: vec![T::Sum::default(); (size.x + 1) * (size.y + 1)],
+        };
+
+        table.accumulate(Point { x: 0, y: 0 }, |x, y| values[y * size.x + x]);
+        table
+    }
+
+    /// Returns the sum of the items at `index`, or
+    /// [`None`](core::option::Option::None) if out of bounds.
+    pub fn sum(&self, index: impl Index2D) -> Option<T::Sum> {
+        let Point { x, y } = index.checked(self.size)?;
+
+        Some(
+            self.at(x.end, y.end) + self.at(x.start, y.start)
+                - self.at(x.start, y.end)
+                - self.at(x.end, y.start),
+        )
+    }
+
+    /// Returns the number of items at `index`, or
+    /// [`None`](core::option::Option::None) if out of bounds.
+    pub fn count(&self, index: impl Index2D) -> Option<usize> {
+        let Point { x, y } = index.checked(self.size)?;
+
+        Some((x.end - x.start) * (y.end - y.start))
+    }
+
+    /// Returns the mean of the items at `index`, or
+    /// [`None`](core::option::Option::None) if out of bounds or empty.
+    pub fn mean(&self, index: impl Index2D) -> Option<f64> {
+        let rect = index.checked(self.size)?;
+        let count = self.count(rect.clone())?;
+
+        if count == 0 {
+            None
+        } else {
+            Some(self.sum(rect)?.to_f64() / count as f64)
+        }
+    }
+
+    /// Rebuilds the table after the items of `grid` at `dirty` changed, or
+    /// returns [`None`](core::option::Option::None) if out of bounds or if
+    /// `grid`'s size differs.
+    ///
+    /// Only the items at `dirty` are read from `grid`, and only the sums
+    /// after `dirty` (down and right) are recomputed.
+    pub fn update<G>(&mut self, grid: G, dirty: impl Index2D) -> Option<()>
+    where
+        G: GridRows,
+        G::Item: Sample<Value = T>,
+    {
+        if grid.size() != self.size {
+            return None;
+        }
+
+        let dirty = dirty.checked(self.size)?;
+        let width = dirty.x.end - dirty.x.start;
+        let changed = collect_rows(grid.cropped(dirty.clone())?, |item| item.value().widen());
+
+        // Items after dirty, from the old sums or from grid
+        let from = Point {
+            x: dirty.x.start,
+            y: dirty.y.start,
+        };
+        let stride = self.size.x - from.x;
+        let mut values = Vec::with_capacity(stride * (self.size.y - from.y));
+
+        for y in from.y..self.size.y {
+            for x in from.x..self.size.x {
+                values.push(if dirty.x.contains(&x) && dirty.y.contains(&y) {
+                    changed[(y - dirty.y.start) * width + x - dirty.x.start]
+                } else {
+                    self.at(x + 1, y + 1) + self.at(x, y) - self.at(x, y + 1) - self.at(x + 1, y)
+                });
+            }
+        }
+
+        self.accumulate(from, |x, y| values[(y - from.y) * stride + x - from.x]);
+        Some(())
+    }
+
+    fn at(&self, x: usize, y: usize) -> T::Sum {
+        self.sums[y * (self.size.x + 1) + x]
+    }
+
+    /// Recomputes the sums after `from`, given items values.
+    fn accumulate(&mut self, from: Point, value: impl Fn(usize, usize) -> T::Sum) {
+        let stride = self.size.x + 1;
+
+        for y in from.y..self.size.y {
+            for x in from.x..self.size.x {
+                let sum = value(x, y) + self.at(x, y + 1) + self.at(x + 1, y) - self.at(x, y);
+
+                self.sums[(y + 1) * stride + x + 1] = sum;
+            }
+        }
+    }
+}
+
+impl<T: Summable> WithSize for SummedArea<T> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn sum() {
+        #[rustfmt::skip]
+        let grid = RowGrid1D::new((4, 3).into(), vec![
+            1u8, 2, 3, 4,
+            5, 6, 7, 8,
+            9, 10, 11, 255,
+        ]).unwrap();
+        let table = SummedArea::new(&grid);
+
+        assert_eq!(table.sum(..), Some(321));
+        assert_eq!(table.sum((1..3, 1..)), Some(34));
+        assert_eq!(table.sum((2..2, ..)), Some(0));
+        assert_eq!(table.sum((0..5, ..)), None);
+        assert_eq!(table.sum((.., 0..4)), None);
+        assert_eq!(table.count((1..3, 1..)), Some(4));
+        assert_eq!(table.mean((1..3, 1..)), Some(8.5));
+        assert_eq!(table.mean((1..1, 1..)), None);
+    }
+
+    #[test]
+    fn update() {
+        let mut grid = RowGrid1D::new((4, 3).into(), vec![1i32; 12]).unwrap();
+        let mut table = SummedArea::new(&grid);
+
+        *(&mut grid).item((1, 1)).unwrap() = -5;
+        *(&mut grid).item((2, 1)).unwrap() = 10;
+        *(&mut grid).item((3, 2)).unwrap() = 3;
+        assert_eq!(table.update(&grid, (1..4, 1..3)), Some(()));
+        assert_eq!(table, SummedArea::new(&grid));
+
+        assert_eq!(table.update(&grid, (.., 0..4)), None);
+        assert_eq!(
+            table.update(&RowGrid1D::new((2, 2).into(), vec![0; 4]).unwrap(), ..),
+            None
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use crate::*;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter, Write},
+};
+
+/// How to handle lines of different widths when parsing a character grid.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Ragged {
+    /// All lines must have the same width.
+    Strict,
+    /// Short lines are padded with this character to the widest line.
+    Pad(char),
+}
+
+/// An error which can be returned when parsing a character grid.
+///
+/// This `struct` is returned by
+/// [`RowGrid1D::parse_str`](Grid1D::parse_str) in
+/// [`Strict`](Ragged::Strict) mode.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ParseGridError {
+    /// The index of the ragged line.
+    pub line:     usize,
+    /// The expected width (the width of the first line).
+    pub expected: usize,
+    /// The actual width of the ragged line.
+    pub actual:   usize,
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {} has {} characters, expected {}",
+            self.line, self.actual, self.expected
+        )
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// ### Text
+impl RowGrid1D<char, Vec<char>> {
+    /// Parses `s` as a character grid, lines being rows, in
+    /// [`Strict`](Ragged::Strict) mode.
+    pub fn parse_str(s: &str) -> Result<Self, ParseGridError> {
+        Self::parse_str_with(s, Ragged::Strict)
+    }
+
+    /// Parses `s` as a character grid, lines being rows, handling lines of
+    /// different widths with `ragged`.
+    pub fn parse_str_with(s: &str, ragged: Ragged) -> Result<Self, ParseGridError> {
+        let lines = s.lines().collect::<Vec<_>>();
+        let widths = lines.iter().map(|line| line.chars().count());
+
+        let x = match ragged {
+            Ragged::Strict => {
+                let expected = widths.clone().next().unwrap_or(0);
+
+                if let Some((line, actual)) =
+                    widths.enumerate().find(|(_, width)| *width != expected)
+                {
+                    return Err(ParseGridError {
+                        line,
+                        expected,
+                        actual,
+                    });
+                }
+
+                expected
+            }
+            Ragged::Pad(_) => widths.max().unwrap_or(0),
+        };
+
+        let mut items = Vec::with_capacity(x * lines.len());
+        for line in &lines {
+            let len = items.len();
+
+            items.extend(line.chars());
+            if let Ragged::Pad(pad) = ragged {
+                items.resize(len + x, pad);
+            }
+        }
+
+        Ok(Self::new_unchecked(Size { x, y: lines.len() }, items))
+    }
+}
+
+/// Horizontal alignment of items in their column.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Align {
+    /// Aligned to the left.
+    Left,
+    /// Aligned to the right.
+    Right,
+    /// Centered (leaning left).
+    Center,
+}
+
+/// Formats grids as text, one line per row.
+///
+/// Columns are as wide as their widest item (or [`width`](DisplayGrid::width)
+/// if wider), and separated by [`separator`](DisplayGrid::separator).
+///
+/// This `struct` is created by
+/// [`ToStringGrid::display`](ToStringGrid::display).
+#[derive(Clone, Debug)]
+pub struct DisplayGrid<G> {
+    grid:      G,
+    separator: String,
+    align:     Align,
+    width:     usize,
+}
+
+impl<G> DisplayGrid<G> {
+    /// Sets the separator between columns (defaults to `""`).
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the alignment of items in their column (defaults to
+    /// [`Left`](Align::Left)).
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the minimum width of columns, in characters (defaults to `0`).
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl<G> Display for DisplayGrid<G>
+where
+    G: GridRows + Clone,
+    G::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // SAFETY: RangeFull is always in bounds
+        let rows = unsafe { self.grid.clone().rows_unchecked(..) }
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = Vec::<usize>::new();
+        for row in &rows {
+            for (i, item) in row.iter().enumerate() {
+                let width = item.chars().count().max(self.width);
+
+                match widths.get_mut(i) {
+                    Some(max) => *max = width.max(*max),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        for (y, row) in rows.iter().enumerate() {
+            if y != 0 {
+                f.write_char('\n')?;
+            }
+
+            for (x, item) in row.iter().enumerate() {
+                if x != 0 {
+                    f.write_str(&self.separator)?;
+                }
+
+                let padding = widths[x] - item.chars().count();
+                let (left, right) = match self.align {
+                    Align::Left => (0, padding),
+                    Align::Right => (padding, 0),
+                    Align::Center => (padding / 2, padding - padding / 2),
+                };
+
+                write!(
+                    f,
+                    "{:left$}{}{:right$}",
+                    "",
+                    item,
+                    "",
+                    left = left,
+                    right = right
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Text formatting of grids.
+///
+/// Implemented for all [`GridRows`](GridRows) of
+/// [`Display`](core::fmt::Display) items.
+pub trait ToStringGrid: GridRows + Clone
+where
+    Self::Item: Display,
+{
+    /// Returns a [`DisplayGrid`](DisplayGrid), to configure text
+    /// formatting.
+    fn display(self) -> DisplayGrid<Self> {
+        DisplayGrid {
+            grid:      self,
+            separator: String::new(),
+            align:     Align::Left,
+            width:     0,
+        }
+    }
+
+    /// Formats the grid as text, one line per row, with the defaults of
+    /// [`DisplayGrid`](DisplayGrid).
+    fn to_string_grid(self) -> String {
+        self.display().to_string()
+    }
+}
+
+impl<G> ToStringGrid for G
+where
+    G: GridRows + Clone,
+    G::Item: Display,
+{
+}
+
+macro_rules! display {
+    ($($Type:ident)*) => { $(
+        impl<I: Display, T: AsRef<[I]>> Display for $Type<I, T> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                self.display().fmt(f)
+            }
+        }
+    )* };
+}
+
+display!(RowGrid1D ColGrid1D);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{format, string::ToString, vec};
+
+    #[test]
+    fn parse_str() {
+        let grid = RowGrid1D::parse_str("ab\ncd\nef\n").unwrap();
+        assert_eq!(grid.size(), Size { x: 2, y: 3 });
+        assert_eq!(grid.as_ref(), &['a', 'b', 'c', 'd', 'e', 'f']);
+
+        assert_eq!(
+            RowGrid1D::parse_str("ab\nc\ndef"),
+            Err(ParseGridError {
+                line:     1,
+                expected: 2,
+                actual:   1,
+            })
+        );
+
+        let grid = RowGrid1D::parse_str_with("ab\nc\r\ndéf", Ragged::Pad('.')).unwrap();
+        assert_eq!(grid.size(), Size { x: 3, y: 3 });
+        assert_eq!(grid.to_string(), "ab.\nc..\ndéf");
+
+        assert_eq!(RowGrid1D::parse_str("").unwrap().size(), Size {
+            x: 0,
+            y: 0,
+        });
+    }
+
+    #[test]
+    fn display() {
+        let grid = RowGrid1D::new((3, 2).into(), vec![1, 20, 3, 400, 5, 6]).unwrap();
+
+        assert_eq!(grid.to_string_grid(), "1  203\n4005 6");
+        assert_eq!(
+            grid.display().separator(" | ").to_string(),
+            "1   | 20 | 3\n400 | 5  | 6"
+        );
+        assert_eq!(
+            grid.display()
+                .separator(",")
+                .align(Align::Right)
+                .to_string(),
+            "  1,20,3\n400, 5,6"
+        );
+        assert_eq!(
+            grid.display()
+                .separator(" ")
+                .align(Align::Center)
+                .width(4)
+                .to_string(),
+            " 1    20   3  \n400   5    6  "
+        );
+
+        let grid = ColGrid1D::new((2, 2).into(), vec!['a', 'b', 'c', 'd']).unwrap();
+        assert_eq!(format!("{}", grid), "ac\nbd");
+        assert_eq!(grid.cropped((1.., ..)).unwrap().to_string_grid(), "c\nd");
+    }
+}
@@ -0,0 +1,15 @@
+use crate::*;
+use alloc::vec::Vec;
+
+/// Collects the items of `grid` in row-major order, mapped with `f`.
+pub(crate) fn collect_rows<G: GridRows, T>(grid: G, mut f: impl FnMut(G::Item) -> T) -> Vec<T> {
+    let size = grid.size();
+    let mut items = Vec::with_capacity(size.x * size.y);
+
+    // SAFETY: RangeFull is always in bounds
+    for row in unsafe { grid.rows_unchecked(..) } {
+        items.extend(row.into_iter().map(&mut f));
+    }
+
+    items
+}
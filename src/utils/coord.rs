@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, ops::Range};
+use core::{cmp::Ordering, ops::Range};
 
 /// Alias of [`Coord<X, Y>`](Coord).
 pub type Point<X = usize, Y = X> = Coord<X, Y>;
@@ -11,6 +11,7 @@ pub type Rect = Coord<Range<usize>>;
 
 /// An x/y pair.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coord<X = usize, Y = X> {
     /// The x axis value.
     pub x: X,
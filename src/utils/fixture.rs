@@ -0,0 +1,31 @@
+use crate::*;
+use alloc::{string::String, vec::Vec};
+
+/// Creates a grid of `size` from the chars of `items` (rows concatenated),
+/// mapped with `f`.
+pub(crate) fn grid_with<T>(
+    size: (usize, usize),
+    items: &str,
+    f: impl FnMut(char) -> T,
+) -> RowGrid1D<T, Vec<T>> {
+    RowGrid1D::new(size.into(), items.chars().map(f).collect()).expect("fixture of size")
+}
+
+/// Creates a grid of `size` from the chars of `items`.
+pub(crate) fn char_grid(size: (usize, usize), items: &str) -> RowGrid1D<char, Vec<char>> {
+    grid_with(size, items, |c| c)
+}
+
+/// Creates a grid of `size` from the chars of `items`, `#` being `true`.
+pub(crate) fn bool_grid(size: (usize, usize), items: &str) -> RowGrid1D<bool, Vec<bool>> {
+    grid_with(size, items, |c| c == '#')
+}
+
+/// Renders the rows of `grid`, mapping items to chars with `f`.
+pub(crate) fn rows_text<G: GridRows>(grid: G, mut f: impl FnMut(G::Item) -> char) -> Vec<String> {
+    grid.rows(..)
+        .expect("RangeFull is always in bounds")
+        .into_iter()
+        .map(|row| row.into_iter().map(&mut f).collect())
+        .collect()
+}
@@ -1,5 +1,13 @@
+#[cfg(feature = "alloc")]
+mod collect;
 mod coord;
+#[cfg(all(test, feature = "alloc"))]
+mod fixture;
 mod range;
 
+#[cfg(feature = "alloc")]
+pub(crate) use collect::*;
 pub use coord::*;
+#[cfg(all(test, feature = "alloc"))]
+pub(crate) use fixture::*;
 pub(crate) use range::*;
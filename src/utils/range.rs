@@ -1,50 +1,37 @@
-use std::ops::{
-    Bound::{self, *},
-    Range,
-    RangeBounds,
-};
+use crate::GridError;
+use core::ops::{Bound::*, Range, RangeBounds};
 
 /// Converts `T: RangeBounds<usize>` to `Range<usize>`.
 pub trait ToRange {
-    /// Converts to `Range` with bounds checking.
-    fn checked(self, len: usize) -> Option<Range<usize>>;
+    /// Converts to `Range` with overflow checking, without bounds checking.
+    ///
+    /// Unbounded ends transform into `len`, or `start` if greater (so that
+    /// only explicit ends give [`InvertedRange`](GridError::InvertedRange)).
+    fn resolved(self, len: usize) -> Result<Range<usize>, GridError>;
 
     /// Converts to `Range` without bounds checking.
     fn unchecked(self, len: usize) -> Range<usize>;
 }
 
 impl<T: RangeBounds<usize>> ToRange for T {
-    fn checked(self, len: usize) -> Option<Range<usize>> {
-        let start = Start::checked(self.start_bound())?;
-        let end = End::checked(self.end_bound())?;
-
-        let (start, end) = match (start, end) {
-            (Start::Included(start), End::Excluded(end)) =>
-                if start <= end && end <= len {
-                    (start, end)
-                } else {
-                    return None;
-                },
-            (Start::Included(start), End::Unbounded) =>
-                if start <= len {
-                    (start, len)
-                } else {
-                    return None;
-                },
-            (Start::Unbounded, End::Excluded(end)) =>
-                if end <= len {
-                    (0, end)
-                } else {
-                    return None;
-                },
-            (Start::Unbounded, End::Unbounded) => (0, len),
+    fn resolved(self, len: usize) -> Result<Range<usize>, GridError> {
+        let start = match self.start_bound() {
+            Included(start) => *start,
+            Excluded(start) => start.checked_add(1).ok_or(GridError::Overflow)?,
+            Unbounded => 0,
         };
 
-        debug_assert!(start <= end);
-        debug_assert!(end <= len);
-        debug_assert!(len <= usize::MAX);
+        let end = match self.end_bound() {
+            Included(end) => end.checked_add(1).ok_or(GridError::Overflow)?,
+            Excluded(end) => *end,
+            Unbounded => len.max(start),
+        };
 
-        Some(start..end)
+        if start <= end {
+            Ok(start..end)
+        } else {
+            Err(GridError::InvertedRange)
+        }
     }
 
     fn unchecked(self, len: usize) -> Range<usize> {
@@ -64,41 +51,17 @@ impl<T: RangeBounds<usize>> ToRange for T {
     }
 }
 
-enum Start {
-    Included(usize),
-    Unbounded,
-}
-
-enum End {
-    Excluded(usize),
-    Unbounded,
-}
-
-impl Start {
-    fn checked(start: Bound<&usize>) -> Option<Self> {
-        Some(match start {
-            Included(start) => Self::Included(*start),
-            Excluded(start) => Self::Included(start.checked_add(1)?),
-            Unbounded => Self::Unbounded,
-        })
-    }
-}
-
-impl End {
-    fn checked(end: Bound<&usize>) -> Option<Self> {
-        Some(match end {
-            Included(end) => Self::Excluded(end.checked_add(1)?),
-            Excluded(end) => Self::Excluded(*end),
-            Unbounded => Self::Unbounded,
-        })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Index1D;
+    use core::ops::Bound;
     use pretty_assertions::assert_eq;
-    use std::ops::Bound;
+
+    /// Bounds checks through [`Index1D`](Index1D).
+    fn checked_range(range: (Bound<usize>, Bound<usize>), len: usize) -> Option<Range<usize>> {
+        (0, range).checked(1, len).map(|(_, range)| range)
+    }
 
     #[test]
     fn checked() {
@@ -108,7 +71,7 @@ mod tests {
                 let end: Bound<usize> = $end;
 
                 assert_eq!(
-                    (start, end).checked($len),
+                    checked_range((start, end), $len),
                     Some($expected),
                     "({:?}, {:?}).checked({:?}) == Some({:?})",
                     start,
@@ -122,7 +85,7 @@ mod tests {
                 let end: Bound<usize> = $end;
 
                 assert_eq!(
-                    (start, end).checked($len),
+                    checked_range((start, end), $len),
                     None,
                     "({:?}, {:?}).checked({:?}) == None",
                     start,
@@ -175,6 +138,26 @@ mod tests {
     }
 
     #[test]
+    fn resolved() {
+        // It does not check bounds, unbounded ends saturating to start
+        assert_eq!((5..20).resolved(10), Ok(5..20));
+        assert_eq!((20..).resolved(10), Ok(20..20));
+        assert_eq!((..).resolved(10), Ok(0..10));
+
+        // It returns errors on inverted ranges and overflows
+        assert_eq!(
+            (Included(7), Included(3)).resolved(10),
+            Err(GridError::InvertedRange)
+        );
+        assert_eq!((..=usize::MAX).resolved(10), Err(GridError::Overflow));
+        assert_eq!(
+            (Excluded(usize::MAX), Unbounded).resolved(10),
+            Err(GridError::Overflow)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn unchecked() {
         fn range(a: Bound<usize>, b: Bound<usize>) -> (Bound<usize>, Bound<usize>) {
             (a, b)
@@ -0,0 +1,650 @@
+//! A virtual terminal, building a screen grid from a byte stream of text
+//! and escape sequences.
+//!
+//! Handles UTF-8 text, C0 controls, common `ESC` sequences and CSI sequences
+//! (cursor movement, erasing, scrolling, scroll regions and SGR). Other
+//! sequences (private modes, OSC, charsets, ...) are consumed and ignored.
+//!
+//! Each character occupies one cell, whatever its display width.
+
+use crate::{
+    cell::{Attributes, Color},
+    *,
+};
+use std::{collections::VecDeque, io, mem, string::String, vec, vec::Vec};
+
+/// A blank cell with the default style.
+const BLANK: (char, Style) = (' ', Style {
+    foreground: None,
+    background: None,
+    attributes: Attributes::NONE,
+});
+
+/// The state of the escape sequence parser.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum State {
+    Ground,
+    Escape,
+    /// An `ESC` sequence with intermediate bytes (e.g. charsets).
+    EscapeIntermediate,
+    Csi,
+    Osc,
+    /// An `ESC` in an OSC, maybe its string terminator.
+    OscEscape,
+}
+
+/// A virtual terminal.
+///
+/// Bytes are [`feed`](Terminal::feed)ed (or [`write`](std::io::Write)n) to
+/// the terminal, which maintains a screen of `(char, Style)` items, the
+/// cursor, and a bounded scrollback of the lines scrolled off the top.
+///
+/// Line feeds do not imply carriage returns.
+#[derive(Clone, Debug)]
+pub struct Terminal {
+    screen:         RowGrid1D<(char, Style), Vec<(char, Style)>>,
+    scrollback:     VecDeque<Vec<(char, Style)>>,
+    scrollback_len: usize,
+    cursor:         Point,
+    /// Whether the next character wraps to the next line first.
+    wrap:           bool,
+    style:          Style,
+    saved:          (Point, Style),
+    /// The scroll region, as a range of rows.
+    top:            usize,
+    bottom:         usize,
+    state:          State,
+    params:         Vec<u16>,
+    private:        bool,
+    utf8:           Vec<u8>,
+}
+
+impl Terminal {
+    /// Creates a new `Terminal` of `size`, keeping at most `scrollback`
+    /// lines in its scrollback.
+    pub fn new(size: Size, scrollback: usize) -> Self {
+        Self {
+            screen:         RowGrid1D::new_unchecked(size, vec![BLANK; size.x * size.y]),
+            scrollback:     VecDeque::new(),
+            scrollback_len: scrollback,
+            cursor:         Point { x: 0, y: 0 },
+            wrap:           false,
+            style:          Style::default(),
+            saved:          (Point { x: 0, y: 0 }, Style::default()),
+            top:            0,
+            bottom:         size.y,
+            state:          State::Ground,
+            params:         Vec::new(),
+            private:        false,
+            utf8:           Vec::new(),
+        }
+    }
+
+    /// Returns the screen.
+    pub fn screen(&self) -> &RowGrid1D<(char, Style), Vec<(char, Style)>> {
+        &self.screen
+    }
+
+    /// Returns the lines scrolled off the screen, oldest first.
+    pub fn scrollback(&self) -> &VecDeque<Vec<(char, Style)>> {
+        &self.scrollback
+    }
+
+    /// Returns the cursor position.
+    pub fn cursor(&self) -> Point {
+        self.cursor
+    }
+
+    /// Returns the current style, for the next characters.
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the text of the screen, one line per row.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+
+        // SAFETY: RangeFull is always in bounds
+        for (y, row) in unsafe { self.screen.rows_unchecked(..) }.enumerate() {
+            if y != 0 {
+                text.push('\n');
+            }
+            text.extend(row.iter().map(|(c, _)| c));
+        }
+
+        text
+    }
+
+    /// Resizes the screen to `size`.
+    ///
+    /// Content is kept from the top left corner. When the cursor would be
+    /// out of the screen, top lines are scrolled into the scrollback first.
+    /// The scroll region is reset.
+    pub fn resize(&mut self, size: Size) {
+        let old = self.screen.size();
+        let shift = (self.cursor.y + 1).saturating_sub(size.y).min(old.y);
+
+        for y in 0..shift {
+            let row = self.screen.as_ref()[y * old.x..(y + 1) * old.x].to_vec();
+            self.push_scrollback(row);
+        }
+
+        let mut items = vec![BLANK; size.x * size.y];
+        for y in 0..size.y.min(old.y - shift) {
+            let len = size.x.min(old.x);
+            let from = (y + shift) * old.x;
+
+            items[y * size.x..y * size.x + len]
+                .copy_from_slice(&self.screen.as_ref()[from..from + len]);
+        }
+
+        self.screen = RowGrid1D::new_unchecked(size, items);
+        self.cursor.y = self.cursor.y.saturating_sub(shift);
+        self.goto(self.cursor.x, self.cursor.y);
+        self.top = 0;
+        self.bottom = size.y;
+    }
+
+    /// Parses `bytes`, updating the screen.
+    ///
+    /// Sequences may be split across calls.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.byte(byte);
+        }
+    }
+
+    fn byte(&mut self, byte: u8) {
+        match self.state {
+            State::Ground => self.ground(byte),
+            State::Escape => self.escape(byte),
+            State::EscapeIntermediate =>
+                if !(0x20..0x30).contains(&byte) {
+                    self.state = State::Ground;
+                },
+            State::Csi => self.csi(byte),
+            State::Osc => match byte {
+                0x07 => self.state = State::Ground,
+                0x1b => self.state = State::OscEscape,
+                _ => {}
+            },
+            State::OscEscape =>
+                if byte == b'\\' {
+                    self.state = State::Ground;
+                } else {
+                    self.state = State::Osc;
+                },
+        }
+    }
+
+    fn ground(&mut self, byte: u8) {
+        if byte >= 0x80 {
+            // Continuation byte, or lead byte of a new character
+            if byte >= 0xc0 || self.utf8.is_empty() {
+                self.flush_utf8();
+            }
+            self.utf8.push(byte);
+
+            let len = match self.utf8[0] {
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf7 => 4,
+                _ => 1,
+            };
+            if self.utf8.len() == len {
+                let c = std::str::from_utf8(&self.utf8)
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+                self.utf8.clear();
+                self.print(c);
+            }
+
+            return;
+        }
+
+        self.flush_utf8();
+        match byte {
+            0x1b => self.state = State::Escape,
+            0x20..=0x7e => self.print(byte as char),
+            _ => self.control(byte),
+        }
+    }
+
+    /// Prints a replacement character for an incomplete UTF-8 sequence.
+    fn flush_utf8(&mut self) {
+        if !self.utf8.is_empty() {
+            self.utf8.clear();
+            self.print(char::REPLACEMENT_CHARACTER);
+        }
+    }
+
+    fn control(&mut self, byte: u8) {
+        match byte {
+            // BS
+            0x08 => self.goto(self.cursor.x.saturating_sub(1), self.cursor.y),
+            // HT
+            0x09 => self.goto((self.cursor.x / 8 + 1) * 8, self.cursor.y),
+            // LF, VT, FF
+            0x0a..=0x0c => self.linefeed(),
+            // CR
+            0x0d => self.goto(0, self.cursor.y),
+            _ => {}
+        }
+    }
+
+    fn escape(&mut self, byte: u8) {
+        self.state = State::Ground;
+
+        match byte {
+            b'[' => {
+                self.state = State::Csi;
+                self.params.clear();
+                self.params.push(0);
+                self.private = false;
+            }
+            b']' => self.state = State::Osc,
+            0x20..=0x2f => self.state = State::EscapeIntermediate,
+            b'7' => self.saved = (self.cursor, self.style),
+            b'8' => {
+                let (cursor, style) = self.saved;
+
+                self.goto(cursor.x, cursor.y);
+                self.style = style;
+            }
+            b'D' => self.linefeed(),
+            b'E' => {
+                self.goto(0, self.cursor.y);
+                self.linefeed();
+            }
+            b'M' => self.reverse_linefeed(),
+            b'c' => *self = Self::new(self.screen.size(), self.scrollback_len),
+            _ => {}
+        }
+    }
+
+    fn csi(&mut self, byte: u8) {
+        match byte {
+            b'0'..=b'9' => {
+                let param = self.params.last_mut().expect("params are not empty");
+
+                *param = param
+                    .saturating_mul(10)
+                    .saturating_add((byte - b'0') as u16);
+            }
+            b';' | b':' => self.params.push(0),
+            b'<'..=b'?' => self.private = true,
+            0x1b => self.state = State::Escape,
+            0x40..=0x7e => {
+                self.state = State::Ground;
+
+                if !self.private {
+                    self.dispatch(byte);
+                }
+            }
+            0x00..=0x1f => self.control(byte),
+            _ => {}
+        }
+    }
+
+    /// Returns parameter `i`, or `default` if missing or `0`.
+    fn param(&self, i: usize, default: usize) -> usize {
+        match self.params.get(i) {
+            Some(&param) if param != 0 => param as usize,
+            _ => default,
+        }
+    }
+
+    fn dispatch(&mut self, byte: u8) {
+        let Size {
+            x: width,
+            y: height,
+        } = self.screen.size();
+        let Point { x, y } = self.cursor;
+        let n = self.param(0, 1);
+
+        match byte {
+            b'A' => self.goto(x, y.saturating_sub(n)),
+            b'B' | b'e' => self.goto(x, y.saturating_add(n)),
+            b'C' | b'a' => self.goto(x.saturating_add(n), y),
+            b'D' => self.goto(x.saturating_sub(n), y),
+            b'E' => self.goto(0, y.saturating_add(n)),
+            b'F' => self.goto(0, y.saturating_sub(n)),
+            b'G' | b'`' => self.goto(n - 1, y),
+            b'd' => self.goto(x, n - 1),
+            b'H' | b'f' => self.goto(self.param(1, 1) - 1, n - 1),
+            b'J' => match self.param(0, 0) {
+                0 => self.erase((y * width + x).min(width * height)..width * height),
+                1 => self.erase(0..(y * width + x + 1).min(width * height)),
+                2 => self.erase(0..width * height),
+                3 => self.scrollback.clear(),
+                _ => {}
+            },
+            // There is no cursor row to edit
+            b'K' | b'X' | b'@' | b'P' if height == 0 => {}
+            b'K' => match self.param(0, 0) {
+                0 => self.erase(y * width + x..(y + 1) * width),
+                1 => self.erase(y * width..(y * width + x + 1).min((y + 1) * width)),
+                2 => self.erase(y * width..(y + 1) * width),
+                _ => {}
+            },
+            b'X' => self.erase(y * width + x..y * width + (x + n).min(width)),
+            b'@' | b'P' => {
+                let n = n.min(width - x);
+                let row = &mut self.screen.as_mut()[y * width + x..(y + 1) * width];
+
+                if byte == b'@' {
+                    row.rotate_right(n);
+                    self.erase(y * width + x..y * width + x + n);
+                } else {
+                    row.rotate_left(n);
+                    self.erase((y + 1) * width - n..(y + 1) * width);
+                }
+            }
+            b'L' if (self.top..self.bottom).contains(&y) => self.scroll_down(y, n),
+            b'M' if (self.top..self.bottom).contains(&y) => self.scroll_up(y, n, false),
+            b'S' => self.scroll_up(self.top, n, self.top == 0),
+            b'T' => self.scroll_down(self.top, n),
+            b'm' => self.sgr(),
+            b'r' => {
+                let top = self.param(0, 1) - 1;
+                let bottom = self.param(1, height).min(height);
+
+                if top + 1 < bottom {
+                    self.top = top;
+                    self.bottom = bottom;
+                    self.goto(0, 0);
+                }
+            }
+            b's' => self.saved = (self.cursor, self.style),
+            b'u' => {
+                let (cursor, style) = self.saved;
+
+                self.goto(cursor.x, cursor.y);
+                self.style = style;
+            }
+            _ => {}
+        }
+    }
+
+    fn sgr(&mut self) {
+        let buffer = mem::take(&mut self.params);
+        let mut params = buffer.iter().map(|&param| param as usize);
+
+        while let Some(param) = params.next() {
+            let style = &mut self.style;
+
+            match param {
+                0 => *style = Style::default(),
+                1 => style.attributes.insert(Attributes::BOLD),
+                2 => style.attributes.insert(Attributes::DIM),
+                3 => style.attributes.insert(Attributes::ITALIC),
+                4 => style.attributes.insert(Attributes::UNDERLINE),
+                5 => style.attributes.insert(Attributes::BLINK),
+                7 => style.attributes.insert(Attributes::REVERSE),
+                8 => style.attributes.insert(Attributes::HIDDEN),
+                9 => style.attributes.insert(Attributes::STRIKE),
+                22 => style.attributes.remove(Attributes::BOLD | Attributes::DIM),
+                23 => style.attributes.remove(Attributes::ITALIC),
+                24 => style.attributes.remove(Attributes::UNDERLINE),
+                25 => style.attributes.remove(Attributes::BLINK),
+                27 => style.attributes.remove(Attributes::REVERSE),
+                28 => style.attributes.remove(Attributes::HIDDEN),
+                29 => style.attributes.remove(Attributes::STRIKE),
+                30..=37 => style.foreground = Some(Color::Ansi((param - 30) as u8)),
+                38 => style.foreground = extended_color(&mut params),
+                39 => style.foreground = None,
+                40..=47 => style.background = Some(Color::Ansi((param - 40) as u8)),
+                48 => style.background = extended_color(&mut params),
+                49 => style.background = None,
+                90..=97 => style.foreground = Some(Color::Ansi((param - 82) as u8)),
+                100..=107 => style.background = Some(Color::Ansi((param - 92) as u8)),
+                _ => {}
+            }
+        }
+
+        self.params = buffer;
+    }
+
+    fn print(&mut self, c: char) {
+        let Size {
+            x: width,
+            y: height,
+        } = self.screen.size();
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        if self.wrap {
+            self.goto(0, self.cursor.y);
+            self.linefeed();
+        }
+
+        let Point { x, y } = self.cursor;
+        self.screen.as_mut()[y * width + x] = (c, self.style);
+
+        if x + 1 < width {
+            self.cursor.x += 1;
+        } else {
+            self.wrap = true;
+        }
+    }
+
+    /// Moves the cursor, clamped to the screen.
+    fn goto(&mut self, x: usize, y: usize) {
+        let Size {
+            x: width,
+            y: height,
+        } = self.screen.size();
+
+        self.cursor = Point {
+            x: x.min(width.saturating_sub(1)),
+            y: y.min(height.saturating_sub(1)),
+        };
+        self.wrap = false;
+    }
+
+    fn linefeed(&mut self) {
+        let y = self.cursor.y;
+
+        if y + 1 == self.bottom {
+            self.scroll_up(self.top, 1, self.top == 0);
+        } else {
+            self.goto(self.cursor.x, y + 1);
+        }
+    }
+
+    fn reverse_linefeed(&mut self) {
+        let y = self.cursor.y;
+
+        if y == self.top {
+            self.scroll_down(self.top, 1);
+        } else {
+            self.goto(self.cursor.x, y.saturating_sub(1));
+        }
+    }
+
+    /// Scrolls rows `top..bottom` up by `n`, saving the rows scrolled off
+    /// to the scrollback if `save`.
+    fn scroll_up(&mut self, top: usize, n: usize, save: bool) {
+        let width = self.screen.size().x;
+        let n = n.min(self.bottom - top);
+
+        if save {
+            for y in top..top + n {
+                let row = self.screen.as_ref()[y * width..(y + 1) * width].to_vec();
+                self.push_scrollback(row);
+            }
+        }
+
+        self.screen.as_mut()[top * width..self.bottom * width].rotate_left(n * width);
+        self.erase((self.bottom - n) * width..self.bottom * width);
+    }
+
+    /// Scrolls rows `top..bottom` down by `n`.
+    fn scroll_down(&mut self, top: usize, n: usize) {
+        let width = self.screen.size().x;
+        let n = n.min(self.bottom - top);
+
+        self.screen.as_mut()[top * width..self.bottom * width].rotate_right(n * width);
+        self.erase(top * width..(top + n) * width);
+    }
+
+    /// Blanks items, with the current background color.
+    fn erase(&mut self, range: std::ops::Range<usize>) {
+        let blank = (' ', Style {
+            background: self.style.background,
+            ..Style::default()
+        });
+
+        self.screen.as_mut()[range]
+            .iter_mut()
+            .for_each(|item| *item = blank);
+    }
+
+    fn push_scrollback(&mut self, row: Vec<(char, Style)>) {
+        if self.scrollback_len == 0 {
+            return;
+        }
+        if self.scrollback.len() == self.scrollback_len {
+            self.scrollback.pop_front();
+        }
+        self.scrollback.push_back(row);
+    }
+}
+
+impl io::Write for Terminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.feed(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Parses the color after SGR `38` or `48`.
+fn extended_color(params: &mut impl Iterator<Item = usize>) -> Option<Color> {
+    let mut next = || params.next().unwrap_or(0) as u8;
+
+    match next() {
+        5 => Some(Color::Indexed(next())),
+        2 => Some(Color::Rgb(next(), next(), next())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::io::Write;
+
+    #[test]
+    fn text() {
+        let mut term = Terminal::new((4, 2).into(), 2);
+
+        term.feed("ab\r\ncdéfg".as_bytes());
+        assert_eq!(term.text(), "cdéf\ng   ");
+        assert_eq!(term.cursor(), Point { x: 1, y: 1 });
+        assert_eq!(term.scrollback().len(), 1);
+
+        // Split UTF-8, tab, backspace, invalid bytes
+        term.feed(&[b'\r', 0xc3]);
+        term.feed(&[0xa9, b'\t', 0x08, 0xff, b'\n']);
+        assert_eq!(term.text(), "é \u{fffd} \n    ");
+        assert_eq!(term.scrollback().len(), 2);
+        assert_eq!(
+            term.scrollback()[0]
+                .iter()
+                .map(|(c, _)| c)
+                .collect::<String>(),
+            "ab  "
+        );
+    }
+
+    #[test]
+    fn csi() {
+        let mut term = Terminal::new((5, 3).into(), 0);
+
+        write!(term, "abcde\r\nfghij\r\nklmno").unwrap();
+        write!(term, "\x1b[2;2H\x1b[K\x1b[A\x1b[2P\x1b[3;3H\x1b[1K").unwrap();
+        assert_eq!(term.text(), "ade  \nf    \n   no");
+
+        write!(term, "\x1b[H\x1b[2J\x1b[?25l\x1b]0;title\x07x\x1b(By").unwrap();
+        assert_eq!(term.text(), "xy   \n     \n     ");
+
+        // Scroll region
+        write!(term, "\x1b[2J\x1b[1;1H1\r\n2\r\n3\x1b[2;3r\x1b[3;1H\n4").unwrap();
+        assert_eq!(term.text(), "1    \n3    \n4    ");
+        write!(term, "\x1b[2;1H\x1bM5").unwrap();
+        assert_eq!(term.text(), "1    \n5    \n3    ");
+    }
+
+    #[test]
+    fn sgr() {
+        let mut term = Terminal::new((3, 1).into(), 0);
+
+        write!(term, "\x1b[1;3;31;48;5;200ma\x1b[22;92;48;2;1;2;3mb\x1b[mc").unwrap();
+        let items = term.screen().as_ref();
+
+        assert_eq!(items[0].1, Style {
+            foreground: Some(Color::Ansi(1)),
+            background: Some(Color::Indexed(200)),
+            attributes: Attributes::BOLD | Attributes::ITALIC,
+        });
+        assert_eq!(items[1].1, Style {
+            foreground: Some(Color::Ansi(10)),
+            background: Some(Color::Rgb(1, 2, 3)),
+            attributes: Attributes::ITALIC,
+        });
+        assert_eq!(items[2].1, Style::default());
+    }
+
+    #[test]
+    fn resize() {
+        let mut term = Terminal::new((3, 3).into(), 10);
+
+        write!(term, "abc\r\ndef\r\ngh").unwrap();
+        term.resize((2, 2).into());
+        assert_eq!(term.text(), "de\ngh");
+        assert_eq!(term.cursor(), Point { x: 1, y: 1 });
+        assert_eq!(term.scrollback().len(), 1);
+
+        term.resize((3, 3).into());
+        assert_eq!(term.text(), "de \ngh \n   ");
+
+        // Resizing to no rows scrolls all of them
+        term.resize((3, 0).into());
+        assert_eq!(term.text(), "");
+        assert_eq!(term.cursor(), Point { x: 1, y: 0 });
+        assert_eq!(term.scrollback().len(), 3);
+
+        term.resize((2, 1).into());
+        assert_eq!(term.text(), "  ");
+    }
+
+    #[test]
+    fn no_rows() {
+        let escapes: [&[u8]; 12] = [
+            b"\x1b[K", b"\x1b[1K", b"\x1b[2K", b"\x1b[X", b"\x1b[@", b"\x1b[P", b"\x1b[J",
+            b"\x1b[1J", b"\x1b[L", b"\x1b[M", b"\x1b[S", b"\x1b[T",
+        ];
+
+        for escape in escapes {
+            let mut term = Terminal::new((3, 0).into(), 0);
+            term.feed(b"\x1b[2Gab");
+            term.feed(escape);
+            assert_eq!(term.text(), "");
+
+            let mut term = Terminal::new((3, 2).into(), 1);
+            term.feed(b"a\r\nbc");
+            term.resize((3, 0).into());
+            term.feed(escape);
+            assert_eq!(term.text(), "");
+        }
+    }
+}
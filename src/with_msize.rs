@@ -2,7 +2,8 @@ use crate::*;
 
 /// [`Size`](Size) as [`Major`](Major) getter.
 ///
-/// Anything that [`Deref`](std::ops::Deref)s into a `WithMSize` is `WithMSize`.
+/// Anything that [`Deref`](core::ops::Deref)s into a `WithMSize` is
+/// `WithMSize`.
 pub trait WithMSize<M: Major>: WithSize {
     /// Returns the [`Size`](Size) as [`Major`](Major).
     fn msize(&self) -> M {
@@ -10,7 +11,7 @@ pub trait WithMSize<M: Major>: WithSize {
     }
 }
 
-impl<M: Major, T: std::ops::Deref<Target = U>, U: WithMSize<M>> WithMSize<M> for T {
+impl<M: Major, T: core::ops::Deref<Target = U>, U: WithMSize<M>> WithMSize<M> for T {
     fn msize(&self) -> M {
         self.deref().msize()
     }